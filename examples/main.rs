@@ -9,7 +9,7 @@ use std::process::{Command};
 fn main() {
     let fork = Fork::from_ptmx().unwrap();
 
-    if let Some(mut master) = fork.is_parent().ok() {
+    if let Ok(mut master) = fork.is_parent() {
         // Read output via PTY master
         let mut output = String::new();
 
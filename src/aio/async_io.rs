@@ -0,0 +1,105 @@
+use ::fork::{ExitNotifier, ExitStatus, Master, Process};
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ::async_io::Async;
+use ::futures_io::{AsyncRead, AsyncWrite};
+
+/// The function `master` switches the master to the nonblocking mode,
+/// shared with its clones, and registers it to the async-io's
+/// reactor. `Async<Master>` is `AsyncRead` and `AsyncWrite`.
+pub fn master(master: Master) -> io::Result<Async<Master>> {
+    Async::new_nonblocking(super::nonblocking(master)?)
+}
+
+/// The function `split` splits the registered master into a reader
+/// and a writer, which can be driven from distinct tasks.
+pub fn split(master: Async<Master>) -> (AsyncMasterReader, AsyncMasterWriter) {
+    let inner = Arc::new(master);
+
+    (AsyncMasterReader { inner: inner.clone() }, AsyncMasterWriter { inner })
+}
+
+/// The struct `AsyncMasterReader` is the reading half of an
+/// `Async<Master>`, returned by `split`.
+#[derive(Debug)]
+pub struct AsyncMasterReader {
+    inner: Arc<Async<Master>>,
+}
+
+/// The struct `AsyncMasterWriter` is the writing half of an
+/// `Async<Master>`, returned by `split`.
+#[derive(Debug)]
+pub struct AsyncMasterWriter {
+    inner: Arc<Async<Master>>,
+}
+
+impl AsyncMasterReader {
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        self.inner.get_ref()
+    }
+}
+
+impl AsyncMasterWriter {
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        self.inner.get_ref()
+    }
+}
+
+impl AsyncRead for AsyncMasterReader {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for AsyncMasterWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.inner).poll_close(cx)
+    }
+}
+
+/// The struct `Exit` is a future of the exit status of a child,
+/// reaped on `SIGCHLD` instead of blocking in `waitpid`(2). The
+/// status is kept by the child's `Process`.
+#[derive(Debug)]
+pub struct Exit<'a> {
+    process: &'a Process,
+    notifier: Async<ExitNotifier<'a>>,
+}
+
+impl<'a> Exit<'a> {
+    /// The constructor function `new` listens to `SIGCHLD` for the
+    /// child's process, like `Child::process`.
+    pub fn new(process: &'a Process) -> io::Result<Self> {
+        Ok(Exit {
+            process,
+            notifier: Async::new_nonblocking(ExitNotifier::new(process)?)?,
+        })
+    }
+}
+
+impl<'a> Future for Exit<'a> {
+    type Output = io::Result<ExitStatus>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let notifier = &self.notifier;
+
+        super::poll_exit(self.process, || {
+            notifier.poll_readable(cx).map_ok(|()| notifier.get_ref().drain())
+        })
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "async-io")]
+pub mod async_io;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+use ::fork::{ExitStatus, Master, Process};
+
+use std::io;
+use std::task::Poll;
+
+/// The function `nonblocking` switches the master to the
+/// nonblocking mode expected by an event loop.
+pub(crate) fn nonblocking(master: Master) -> io::Result<Master> {
+    master.set_nonblocking(true)?;
+    Ok(master)
+}
+
+/// The function `poll_exit` reaps the child through its process, or
+/// waits for the next `SIGCHLD` through `poll_sigchld`. The listener
+/// of `SIGCHLD` must be registered before the first call, so that no
+/// exit is missed.
+pub(crate) fn poll_exit<F>(process: &Process, mut poll_sigchld: F) -> Poll<io::Result<ExitStatus>>
+    where F: FnMut() -> Poll<io::Result<()>>
+{
+    loop {
+        match process.try_wait() {
+            Err(err) => return Poll::Ready(Err(err)),
+            Ok(Some(status)) => return Poll::Ready(Ok(status)),
+            Ok(None) => {
+                match poll_sigchld() {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Ready(Ok(())) => continue,
+                }
+            }
+        }
+    }
+}
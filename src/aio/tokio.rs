@@ -0,0 +1,208 @@
+use ::fork::{ExitStatus, Master, Process};
+
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ::tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use ::tokio::io::unix::AsyncFd;
+use ::tokio::signal::unix::{signal, Signal, SignalKind};
+
+/// The struct `AsyncMaster` drives a master's pty from the tokio's
+/// reactor, which must be running when it's created.
+#[derive(Debug)]
+pub struct AsyncMaster {
+    inner: AsyncFd<Master>,
+}
+
+impl AsyncMaster {
+    /// The constructor function `new` switches the master to the
+    /// nonblocking mode, shared with its clones, and registers it to
+    /// the reactor.
+    pub fn new(master: Master) -> io::Result<Self> {
+        let master = super::nonblocking(master)?;
+
+        // The fd is open, and only closed when the master is dropped.
+        match unsafe { AsyncFd::register(master) } {
+            Err(err) => Err(err.into_parts().1),
+            Ok(inner) => Ok(AsyncMaster { inner }),
+        }
+    }
+
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        self.inner.get_ref()
+    }
+
+    /// The function `into_inner` deregisters the master's pty
+    /// and returns it, still in the nonblocking mode.
+    pub fn into_inner(self) -> Master {
+        self.inner.into_inner()
+    }
+}
+
+impl AsyncMaster {
+    /// Splits the master into a reader and a writer, which can be
+    /// driven from distinct tasks, sharing the registration.
+    pub fn split(self) -> (AsyncMasterReader, AsyncMasterWriter) {
+        let inner = Arc::new(self.inner);
+
+        (AsyncMasterReader { inner: inner.clone() }, AsyncMasterWriter { inner })
+    }
+}
+
+/// The struct `AsyncMasterReader` is the reading half of an
+/// `AsyncMaster`, returned by `AsyncMaster::split`.
+#[derive(Debug)]
+pub struct AsyncMasterReader {
+    inner: Arc<AsyncFd<Master>>,
+}
+
+/// The struct `AsyncMasterWriter` is the writing half of an
+/// `AsyncMaster`, returned by `AsyncMaster::split`.
+#[derive(Debug)]
+pub struct AsyncMasterWriter {
+    inner: Arc<AsyncFd<Master>>,
+}
+
+impl AsyncMasterReader {
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        self.inner.get_ref()
+    }
+}
+
+impl AsyncMasterWriter {
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        self.inner.get_ref()
+    }
+}
+
+/// The function `poll_read` reads from the pty once it's readable.
+fn poll_read(inner: &AsyncFd<Master>, cx: &mut Context, buf: &mut ReadBuf) -> Poll<io::Result<()>> {
+    loop {
+        let mut guard = match inner.poll_read_ready(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+            Poll::Ready(Ok(guard)) => guard,
+        };
+
+        match guard.try_io(|inner| {
+            let mut master = inner.get_ref();
+
+            master.read(buf.initialize_unfilled())
+        }) {
+            Err(_would_block) => continue,
+            Ok(Err(err)) => return Poll::Ready(Err(err)),
+            Ok(Ok(len)) => {
+                buf.advance(len);
+                return Poll::Ready(Ok(()));
+            }
+        }
+    }
+}
+
+/// The function `poll_write` writes to the pty once it's writable.
+fn poll_write(inner: &AsyncFd<Master>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+    loop {
+        let mut guard = match inner.poll_write_ready(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+            Poll::Ready(Ok(guard)) => guard,
+        };
+
+        match guard.try_io(|inner| {
+            let mut master = inner.get_ref();
+
+            master.write(buf)
+        }) {
+            Err(_would_block) => continue,
+            Ok(result) => return Poll::Ready(result),
+        }
+    }
+}
+
+impl AsyncRead for AsyncMaster {
+    fn poll_read(self: Pin<&mut Self>,
+                 cx: &mut Context,
+                 buf: &mut ReadBuf)
+                 -> Poll<io::Result<()>> {
+        poll_read(&self.inner, cx, buf)
+    }
+}
+
+impl AsyncWrite for AsyncMaster {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        poll_write(&self.inner, cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncRead for AsyncMasterReader {
+    fn poll_read(self: Pin<&mut Self>,
+                 cx: &mut Context,
+                 buf: &mut ReadBuf)
+                 -> Poll<io::Result<()>> {
+        poll_read(&self.inner, cx, buf)
+    }
+}
+
+impl AsyncWrite for AsyncMasterWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        poll_write(&self.inner, cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// The struct `Exit` is a future of the exit status of a child,
+/// reaped on `SIGCHLD` instead of blocking in `waitpid`(2). The
+/// status is kept by the child's `Process`.
+#[derive(Debug)]
+pub struct Exit<'a> {
+    process: &'a Process,
+    sigchld: Signal,
+}
+
+impl<'a> Exit<'a> {
+    /// The constructor function `new` listens to `SIGCHLD` for the
+    /// child's process, like `Child::process`. The tokio's runtime
+    /// must be running.
+    pub fn new(process: &'a Process) -> io::Result<Self> {
+        Ok(Exit {
+            process,
+            sigchld: signal(SignalKind::child())?,
+        })
+    }
+}
+
+impl<'a> Future for Exit<'a> {
+    type Output = io::Result<ExitStatus>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let process = self.process;
+        let sigchld = &mut self.sigchld;
+
+        super::poll_exit(process, || {
+            sigchld.poll_recv(cx).map(|received| {
+                received.ok_or_else(|| io::Error::other("the SIGCHLD listener is gone"))
+            })
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use ::fork::{MasterError, SpawnError};
+
+/// The alias `Result` learns `CommandError` possibility.
+pub type Result<T> = ::std::result::Result<T, CommandError>;
+
+/// The enum `CommandError` defines the possible errors from `Command::spawn`.
+#[derive(Debug)]
+pub enum CommandError {
+    /// Can't creates the child.
+    Failure(io::Error),
+    /// Can't suspending the calling process.
+    WaitpidFail(io::Error),
+    /// Can't send the signal.
+    KillFail(io::Error),
+    /// The program, an argument, a variable or the directory
+    /// contains a nul byte.
+    NulByte,
+    /// The Master occured a error.
+    BadMaster(MasterError),
+    /// The child's setup or the exec failed.
+    Spawn(SpawnError),
+}
+
+impl fmt::Display for CommandError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CommandError::Failure(ref err) => write!(f, "fork: {}", err),
+            CommandError::WaitpidFail(ref err) => write!(f, "waitpid: {}", err),
+            CommandError::KillFail(ref err) => write!(f, "kill: {}", err),
+            CommandError::BadMaster(ref err) => write!(f, "master: {}", err),
+            CommandError::Spawn(ref err) => write!(f, "child: {}", err),
+            CommandError::NulByte => write!(f, "a string given to the command contains a nul byte"),
+        }
+    }
+}
+
+impl Error for CommandError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            CommandError::Failure(_) => {
+                "On failure, -1 is returned in the parent,no child process is created, and errno \
+                 isset appropriately."
+            }
+            CommandError::WaitpidFail(_) => "Can't suspending the calling process.",
+            CommandError::KillFail(_) => "Can't send the signal.",
+            CommandError::NulByte => "a string given to the command contains a nul byte",
+            CommandError::BadMaster(_) => "the master as occured an error",
+            CommandError::Spawn(_) => "the child can't be set up or exec the program",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CommandError::Failure(ref err) => Some(err),
+            CommandError::WaitpidFail(ref err) => Some(err),
+            CommandError::KillFail(ref err) => Some(err),
+            CommandError::BadMaster(ref err) => Some(err),
+            CommandError::Spawn(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CommandError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: CommandError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -0,0 +1,87 @@
+use ::fork::{self, Master, Termios, WindowSize};
+
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::process::CommandExt as StdCommandExt;
+use std::process;
+
+/// The trait `CommandExt` runs a `std::process::Command` inside
+/// a new PTY, keeping the std's handling of the environment,
+/// the working directory and the credentials.
+pub trait CommandExt {
+    /// The function `spawn_pty` spawns the command with the slave
+    /// of a new PTY as standard streams and controlling terminal,
+    /// resized to `size` and configured with the `termios` preset
+    /// if any, like `Termios::raw`. The command is consumed: its
+    /// `pre_exec` hook attaches this PTY, it can't spawn another one.
+    fn spawn_pty(self,
+                 size: Option<WindowSize>,
+                 termios: Option<fn(Termios) -> Termios>)
+                 -> io::Result<PtyChild>;
+
+    /// The function `spawn_pty_with` spawns the command like
+    /// `spawn_pty`. The slave becomes the controlling terminal only
+    /// if `controlling`, like with `Command::controlling_terminal`.
+    fn spawn_pty_with(self,
+                      size: Option<WindowSize>,
+                      termios: Option<fn(Termios) -> Termios>,
+                      controlling: bool)
+                      -> io::Result<PtyChild>;
+}
+
+impl CommandExt for process::Command {
+    fn spawn_pty(self,
+                 size: Option<WindowSize>,
+                 termios: Option<fn(Termios) -> Termios>)
+                 -> io::Result<PtyChild> {
+        self.spawn_pty_with(size, termios, true)
+    }
+
+    fn spawn_pty_with(mut self,
+                      size: Option<WindowSize>,
+                      termios: Option<fn(Termios) -> Termios>,
+                      controlling: bool)
+                      -> io::Result<PtyChild> {
+        let master = fork::unlocked_master(::DEFAULT_PTMX, size, termios)
+            .map_err(io::Error::from)?;
+        let ptsname = fork::slave_name(&master)?;
+        let fd = master.as_raw_fd();
+
+        unsafe {
+            self.pre_exec(move || {
+                // The master is close-on-exec. Its number may now be a
+                // standard stream the slave was dup2'd onto: keep it.
+                match fork::attach_slave(fd, &ptsname, controlling) {
+                    Err(cause) => Err(io::Error::from_raw_os_error(cause.errno())),
+                    Ok(slave) => {
+                        super::release(slave);
+                        Ok(())
+                    }
+                }
+            });
+        }
+
+        self.spawn().map(|child| PtyChild { child, master })
+    }
+}
+
+/// The struct `PtyChild` is the parent's side of a command spawned
+/// by `CommandExt::spawn_pty`.
+#[derive(Debug)]
+pub struct PtyChild {
+    child: process::Child,
+    master: Master,
+}
+
+impl PtyChild {
+    /// The accessor function `child` returns the std's child handle.
+    pub fn child(&mut self) -> &mut process::Child {
+        &mut self.child
+    }
+
+    /// The accessor function `master` returns the master's pty.
+    pub fn master(&self) -> &Master {
+        &self.master
+    }
+}
+
@@ -0,0 +1,326 @@
+mod err;
+mod ext;
+
+use ::descriptor::Descriptor;
+use ::fork::{self, ExitStatus, Master, Process, ShutdownPolicy, Signal};
+use ::fork::{Slave, Termios, WindowSize};
+use ::fork::{spawn, SpawnError};
+use ::fork::spawn::Forked;
+use ::libc;
+
+pub use self::err::{CommandError, Result};
+pub use self::ext::{CommandExt, PtyChild};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::env;
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::io;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, IntoRawFd};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::time::Duration;
+
+extern "C" {
+    static mut environ: *const *const libc::c_char;
+}
+
+/// The struct `Command` is a process builder which runs a program
+/// on the slave of a new PTY. Unlike `Fork`, the child never comes
+/// back to Rust code: the program is exec'd as soon as the slave is
+/// plugged on its standard streams.
+#[derive(Clone, Debug)]
+pub struct Command {
+    program: OsString,
+    args: Vec<OsString>,
+    env: BTreeMap<OsString, Option<OsString>>,
+    env_clear: bool,
+    cwd: Option<PathBuf>,
+    window_size: Option<WindowSize>,
+    termios: Option<fn(Termios) -> Termios>,
+    controlling_terminal: bool,
+}
+
+impl Command {
+    /// The constructor function `new` returns a builder for `program`,
+    /// looked up in the `PATH` like `execvp`(3) does.
+    pub fn new<S: AsRef<OsStr>>(program: S) -> Self {
+        Command {
+            program: program.as_ref().to_owned(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            env_clear: false,
+            cwd: None,
+            window_size: None,
+            termios: None,
+            controlling_terminal: true,
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds several arguments to pass to the program.
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+        where I: IntoIterator<Item = S>,
+              S: AsRef<OsStr>
+    {
+        for arg in args {
+            self.arg(arg);
+        }
+        self
+    }
+
+    /// Sets an environment variable of the program.
+    pub fn env<K, V>(&mut self, key: K, val: V) -> &mut Self
+        where K: AsRef<OsStr>,
+              V: AsRef<OsStr>
+    {
+        self.env.insert(key.as_ref().to_owned(), Some(val.as_ref().to_owned()));
+        self
+    }
+
+    /// Removes an environment variable of the program.
+    pub fn env_remove<K: AsRef<OsStr>>(&mut self, key: K) -> &mut Self {
+        self.env.insert(key.as_ref().to_owned(), None);
+        self
+    }
+
+    /// Clears the environment inherited from the parent.
+    pub fn env_clear(&mut self) -> &mut Self {
+        self.env.clear();
+        self.env_clear = true;
+        self
+    }
+
+    /// Sets the working directory of the program.
+    pub fn current_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        self.cwd = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the initial window size of the pty.
+    pub fn window_size(&mut self, size: WindowSize) -> &mut Self {
+        self.window_size = Some(size);
+        self
+    }
+
+    /// Sets a change of the line discipline's configuration, like
+    /// `Termios::raw`, applied to the pty before the child starts.
+    pub fn termios(&mut self, preset: fn(Termios) -> Termios) -> &mut Self {
+        self.termios = Some(preset);
+        self
+    }
+
+    /// Sets whether the pty becomes the controlling terminal of the
+    /// child, which is the default. Without it, the child has no
+    /// `/dev/tty`, job control nor signals from the line discipline.
+    pub fn controlling_terminal(&mut self, controlling: bool) -> &mut Self {
+        self.controlling_terminal = controlling;
+        self
+    }
+
+    /// The function `spawn` forks with a new PTY, execs the program
+    /// in the child and returns the parent's side.
+    pub fn spawn(&self) -> Result<Child> {
+        let mut args = vec![cstring(&self.program)?];
+
+        for arg in &self.args {
+            args.push(cstring(arg)?);
+        }
+
+        let envs = match self.capture_env() {
+            None => None,
+            Some(vars) => {
+                let mut envs = Vec::with_capacity(vars.len());
+
+                for (key, val) in vars {
+                    let mut pair = key;
+
+                    pair.push("=");
+                    pair.push(val);
+                    envs.push(cstring(&pair)?);
+                }
+                Some(envs)
+            }
+        };
+        let cwd = match self.cwd {
+            None => None,
+            Some(ref dir) => Some(cstring(dir.as_os_str())?),
+        };
+        let argv = pointers(&args);
+        let envp = envs.as_ref().map(|envs| pointers(envs));
+
+        let master = fork::unlocked_master(::DEFAULT_PTMX, self.window_size, self.termios).map_err(CommandError::BadMaster)?;
+        let ptsname = fork::slave_name(&master).map_err(CommandError::BadMaster)?;
+        let controlling = self.controlling_terminal;
+
+        match unsafe {
+            spawn::fork(|| Err::<Infallible, _>(exec(&master, &ptsname, controlling, &argv, envp.as_ref(), cwd.as_ref())))
+        } {
+            Err(err) => Err(CommandError::Failure(err)),
+            Ok(Err(cause)) => Err(CommandError::Spawn(cause)),
+            Ok(Ok(Forked::Parent(pid))) => {
+                Ok(Child {
+                    process: Process::new(pid),
+                    master,
+                })
+            }
+            Ok(Ok(Forked::Child(never))) => match never {},
+        }
+    }
+
+    /// The function `capture_env` returns the whole environment of
+    /// the program, or none when it's just the parent's one.
+    fn capture_env(&self) -> Option<BTreeMap<OsString, OsString>> {
+        if !self.env_clear && self.env.is_empty() {
+            None
+        } else {
+            let mut vars: BTreeMap<OsString, OsString> = if self.env_clear {
+                BTreeMap::new()
+            } else {
+                env::vars_os().collect()
+            };
+
+            for (key, val) in &self.env {
+                match *val {
+                    Some(ref val) => vars.insert(key.clone(), val.clone()),
+                    None => vars.remove(key),
+                };
+            }
+            Some(vars)
+        }
+    }
+}
+
+/// The struct `Child` is the parent's side of a spawned `Command`.
+#[derive(Debug)]
+pub struct Child {
+    process: Process,
+    master: Master,
+}
+
+impl Child {
+    /// The accessor function `pid` returns the child's pid.
+    pub fn pid(&self) -> libc::pid_t {
+        self.process.pid()
+    }
+
+    /// The accessor function `master` returns the master's pty,
+    /// closed when the child is dropped.
+    pub fn master(&self) -> &Master {
+        &self.master
+    }
+
+    /// The function `into_master` returns the master's pty,
+    /// without waiting for the child.
+    pub fn into_master(self) -> Master {
+        self.master
+    }
+
+    /// The accessor function `process` returns the parent's handle
+    /// on the child.
+    pub fn process(&self) -> &Process {
+        &self.process
+    }
+
+    /// Waits for the child, see `Process::wait`.
+    pub fn wait(&self) -> Result<ExitStatus> {
+        self.process.wait().map_err(CommandError::WaitpidFail)
+    }
+
+    /// Checks on the child without blocking, see `Process::try_wait`.
+    pub fn try_wait(&self) -> Result<Option<ExitStatus>> {
+        self.process.try_wait().map_err(CommandError::WaitpidFail)
+    }
+
+    /// Waits for the child during at most `timeout`, see
+    /// `Process::wait_timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<ExitStatus>> {
+        self.process.wait_timeout(timeout).map_err(CommandError::WaitpidFail)
+    }
+
+    /// Stops the child, see `Process::shutdown`.
+    pub fn shutdown(&self, policy: ShutdownPolicy) -> Result<ExitStatus> {
+        self.process.shutdown(policy).map_err(CommandError::WaitpidFail)
+    }
+
+    /// Signals the child, see `Process::kill`.
+    pub fn kill(&self, signal: Signal) -> Result<()> {
+        self.process.kill(signal).map_err(CommandError::KillFail)
+    }
+
+    /// Signals the child's process group, see `Process::kill_group`.
+    pub fn kill_group(&self, signal: Signal) -> Result<()> {
+        self.process.kill_group(signal).map_err(CommandError::KillFail)
+    }
+
+    /// Signals the foreground job of the pty, see
+    /// `Process::kill_foreground`.
+    pub fn kill_foreground(&self, signal: Signal) -> Result<()> {
+        self.process.kill_foreground(&self.master, signal).map_err(CommandError::KillFail)
+    }
+}
+
+/// The function `cstring` converts a string given to the builder.
+fn cstring(s: &OsStr) -> Result<CString> {
+    CString::new(s.as_bytes()).map_err(|_| CommandError::NulByte)
+}
+
+/// The function `pointers` returns the null-terminated array
+/// of pointers expected by `execvp`(3).
+fn pointers(strings: &[CString]) -> Vec<*const libc::c_char> {
+    strings.iter().map(|s| s.as_ptr()).chain(Some(ptr::null())).collect()
+}
+
+/// The function `exec` runs in the child: it plugs the slave and
+/// replaces the process image, or returns the failed step.
+/// Everything was allocated before `fork`(2), so only
+/// async-signal-safe calls remain here.
+unsafe fn exec(master: &Master,
+               ptsname: &CStr,
+               controlling: bool,
+               argv: &[*const libc::c_char],
+               envp: Option<&Vec<*const libc::c_char>>,
+               cwd: Option<&CString>)
+               -> SpawnError {
+    let errno = || io::Error::last_os_error().raw_os_error().unwrap_or(0);
+
+    match fork::attach_slave(master.as_raw_fd(), ptsname, controlling) {
+        Err(cause) => return cause,
+        Ok(slave) => release(slave),
+    }
+    // `execvp`(3) closes the master: closing it here could hit the
+    // standard stream dup2'd over its number.
+
+    let mut set: libc::sigset_t = mem::zeroed();
+
+    libc::sigemptyset(&mut set);
+    libc::pthread_sigmask(libc::SIG_SETMASK, &set, ptr::null_mut());
+    libc::signal(libc::SIGPIPE, libc::SIG_DFL);
+
+    if let Some(dir) = cwd {
+        if libc::chdir(dir.as_ptr()) == -1 {
+            return SpawnError::Chdir { errno: errno() };
+        }
+    }
+    if let Some(envp) = envp {
+        environ = envp.as_ptr();
+    }
+    libc::execvp(argv[0], argv.as_ptr());
+    SpawnError::Exec { errno: errno() }
+}
+
+/// The function `release` closes the slave's own fd once it's
+/// plugged on the standard streams of the child.
+fn release(slave: Slave) {
+    if slave.as_raw_fd() > libc::STDERR_FILENO {
+        let _ = slave.close();
+    } else {
+        let _ = slave.into_raw_fd();
+    }
+}
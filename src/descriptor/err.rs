@@ -1,24 +1,27 @@
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 /// The alias `Result` learns `DescriptorError` possibility.
-
 pub type Result<T> = ::std::result::Result<T, DescriptorError>;
 
 /// The enum `DescriptorError` defines the possible errors
 /// from constructor Descriptor.
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum DescriptorError {
     /// Can't open.
-    OpenFail,
+    OpenFail(io::Error),
     /// Can't closed.
-    CloseFail,
+    CloseFail(io::Error),
 }
 
 impl fmt::Display for DescriptorError {
     /// The function `fmt` formats the value using the given formatter.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", ::errno::errno())
+        match *self {
+            DescriptorError::OpenFail(ref err) => write!(f, "open: {}", err),
+            DescriptorError::CloseFail(ref err) => write!(f, "close: {}", err),
+        }
     }
 }
 
@@ -26,14 +29,23 @@ impl Error for DescriptorError {
     /// The function `description` returns a short description of the error.
     fn description(&self) -> &str {
         match *self {
-            DescriptorError::OpenFail => "can't open the fd",
-            DescriptorError::CloseFail => "can't close the fd",
+            DescriptorError::OpenFail(_) => "can't open the fd",
+            DescriptorError::CloseFail(_) => "can't close the fd",
         }
     }
 
-    /// The function `cause` returns the lower-level cause of this error, if any.
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DescriptorError::OpenFail(ref err) => Some(err),
+            DescriptorError::CloseFail(ref err) => Some(err),
+        }
+    }
+}
 
-    fn cause(&self) -> Option<&Error> {
-        None
+impl From<DescriptorError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: DescriptorError) -> Self {
+        io::Error::new(::error_kind(&err), err)
     }
 }
@@ -2,39 +2,79 @@ mod err;
 
 use ::libc;
 
-pub use self::err::DescriptorError;
-use std::os::unix::io::{AsRawFd, RawFd};
+pub use self::err::{DescriptorError, Result};
+use std::ffi::CStr;
+use std::io;
+use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
 
-pub trait Descriptor: AsRawFd {
-    /// The constructor function `open` opens the path
-    /// and returns the fd.
-    fn open(path: *const libc::c_char,
-            flag: libc::c_int,
-            mode: Option<libc::c_int>)
-            -> Result<RawFd, DescriptorError> {
-        unsafe {
-            match libc::open(path, flag, mode.unwrap_or_default()) {
-                -1 => Err(DescriptorError::OpenFail),
-                fd => Ok(fd),
-            }
+/// The function `open` opens the path and returns the fd, closed on
+/// exec so it doesn't leak into the programs run meanwhile.
+pub fn open(path: &CStr, flag: libc::c_int, mode: Option<libc::c_int>) -> Result<RawFd> {
+    unsafe {
+        match libc::open(path.as_ptr(), flag | libc::O_CLOEXEC, mode.unwrap_or_default()) {
+            -1 => Err(DescriptorError::OpenFail(io::Error::last_os_error())),
+            fd => Ok(fd),
         }
     }
+}
 
-    /// The function `close` leaves the fd.
-    fn close(&self) -> Result<(), DescriptorError> {
-        unsafe {
-            match libc::close(self.as_raw_fd()) {
-                -1 => Err(DescriptorError::CloseFail),
-                _ => Ok(()),
+/// The function `cloexec_pipe` returns a pipe closed on exec, like
+/// `open`, with the status flags, like `O_NONBLOCK`, on both ends.
+/// Where `pipe2`(2) exists, both are set before another thread can
+/// fork and exec with the pipe open.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cloexec_pipe(flags: libc::c_int) -> io::Result<(RawFd, RawFd)> {
+    let mut fds = [-1; 2];
+
+    if unsafe { libc::pipe2(fds.as_mut_ptr(), flags | libc::O_CLOEXEC) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok((fds[0], fds[1]))
+}
+
+/// The fallback of `cloexec_pipe` without `pipe2`(2).
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub(crate) fn cloexec_pipe(flags: libc::c_int) -> io::Result<(RawFd, RawFd)> {
+    let mut fds = [-1; 2];
+
+    unsafe {
+        if libc::pipe(fds.as_mut_ptr()) == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        for &fd in &fds {
+            if libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) == -1 ||
+               libc::fcntl(fd, libc::F_SETFL, flags) == -1 {
+                let err = io::Error::last_os_error();
+
+                libc::close(fds[0]);
+                libc::close(fds[1]);
+                return Err(err);
             }
         }
     }
+    Ok((fds[0], fds[1]))
+}
 
-    /// The destructor function `drop` call the method `close`
-    /// and panic if a error is occurred.
-    fn drop(&self) {
-        if self.close().is_err() {
-            unimplemented!();
+/// The trait `Descriptor` is implemented by the owners of a fd,
+/// which is closed when they're dropped. The error of `close`(2)
+/// is ignored then; the method `close` returns it instead.
+pub trait Descriptor: AsRawFd + IntoRawFd {
+    /// The constructor function `open` opens the path
+    /// and returns the fd.
+    fn open(path: &CStr, flag: libc::c_int, mode: Option<libc::c_int>) -> Result<RawFd> {
+        open(path, flag, mode)
+    }
+
+    /// The function `close` leaves the fd. It isn't retried on
+    /// `EINTR`, the fd being released anyway.
+    fn close(self) -> Result<()>
+        where Self: Sized
+    {
+        unsafe {
+            match libc::close(self.into_raw_fd()) {
+                -1 => Err(DescriptorError::CloseFail(io::Error::last_os_error())),
+                _ => Ok(()),
+            }
         }
     }
 }
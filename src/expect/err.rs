@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The alias `Result` learns `ExpectError` possibility.
+pub type Result<T> = ::std::result::Result<T, ExpectError>;
+
+/// The enum `ExpectError` defines the possible errors from `Expect`.
+/// The failed expectations keep the transcript of the output seen
+/// since the last match.
+#[derive(Debug)]
+pub enum ExpectError {
+    /// Can't wait for the master.
+    PollFail(io::Error),
+    /// Can't read the master.
+    ReadFail(io::Error),
+    /// Can't write to the master.
+    WriteFail(io::Error),
+    /// Nothing matched before the timeout.
+    Timeout { transcript: Vec<u8> },
+    /// Nothing matched before the child hung up the pty.
+    Eof { transcript: Vec<u8> },
+    /// The character has no control code.
+    BadControl(char),
+}
+
+impl ExpectError {
+    /// The accessor function `transcript` returns the output seen
+    /// before a failed expectation.
+    pub fn transcript(&self) -> Option<&[u8]> {
+        match *self {
+            ExpectError::Timeout { ref transcript } |
+            ExpectError::Eof { ref transcript } => Some(transcript),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ExpectError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExpectError::PollFail(ref err) => write!(f, "poll: {}", err),
+            ExpectError::ReadFail(ref err) => write!(f, "read: {}", err),
+            ExpectError::WriteFail(ref err) => write!(f, "write: {}", err),
+            ExpectError::Timeout { ref transcript } => {
+                write!(f, "timed out, seen: {:?}", String::from_utf8_lossy(transcript))
+            }
+            ExpectError::Eof { ref transcript } => {
+                write!(f, "hung up, seen: {:?}", String::from_utf8_lossy(transcript))
+            }
+            ExpectError::BadControl(c) => write!(f, "no control code for {:?}", c),
+        }
+    }
+}
+
+impl Error for ExpectError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            ExpectError::PollFail(_) => "the `poll` has a error, errno is set appropriately.",
+            ExpectError::ReadFail(_) => "the `read` has a error, errno is set appropriately.",
+            ExpectError::WriteFail(_) => "the `write` has a error, errno is set appropriately.",
+            ExpectError::Timeout { .. } => "nothing matched before the timeout",
+            ExpectError::Eof { .. } => "nothing matched before the hangup",
+            ExpectError::BadControl(_) => "the character has no control code",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ExpectError::PollFail(ref err) |
+            ExpectError::ReadFail(ref err) |
+            ExpectError::WriteFail(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ExpectError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: ExpectError) -> Self {
+        let kind = match err {
+            ExpectError::Timeout { .. } => io::ErrorKind::TimedOut,
+            ExpectError::Eof { .. } => io::ErrorKind::UnexpectedEof,
+            ExpectError::BadControl(_) => io::ErrorKind::InvalidInput,
+            _ => ::error_kind(&err),
+        };
+
+        io::Error::new(kind, err)
+    }
+}
@@ -0,0 +1,290 @@
+mod err;
+
+use ::fork::Master;
+use ::poll::{self, Interest, PollFd};
+
+pub use self::err::{ExpectError, Result};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "regex")]
+use ::regex::bytes::Regex;
+
+/// The default time an expectation waits for a match.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The default number of unmatched bytes kept in the buffer.
+pub const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// The size of each read from the master.
+const CHUNK: usize = 4096;
+
+/// The enum `Pattern` defines what an expectation waits for.
+#[derive(Clone, Debug)]
+pub enum Pattern {
+    /// The text appears in the output.
+    Exact(Vec<u8>),
+    /// The regular expression matches the output.
+    #[cfg(feature = "regex")]
+    Regex(Regex),
+    /// The child hangs up the pty.
+    Eof,
+    /// Nothing else matched before the timeout.
+    Timeout,
+}
+
+impl<'a> From<&'a str> for Pattern {
+    fn from(text: &'a str) -> Self {
+        Pattern::Exact(text.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for Pattern {
+    fn from(text: String) -> Self {
+        Pattern::Exact(text.into_bytes())
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<Regex> for Pattern {
+    fn from(regex: Regex) -> Self {
+        Pattern::Regex(regex)
+    }
+}
+
+/// The struct `Match` is the output consumed by a fulfilled
+/// expectation: what came before the match, and its groups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    index: usize,
+    before: Vec<u8>,
+    groups: Vec<Option<Vec<u8>>>,
+}
+
+impl Match {
+    /// The accessor function `index` returns the position of the
+    /// matching pattern given to `expect_any`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The accessor function `before` returns the output which
+    /// preceded the match.
+    pub fn before(&self) -> &[u8] {
+        &self.before
+    }
+
+    /// The accessor function `as_bytes` returns the matched output,
+    /// empty for `Eof` and `Timeout`.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.get(0).unwrap_or_default()
+    }
+
+    /// Returns the capture group of a regular expression, the group
+    /// 0 being the whole match, or none if it didn't participate.
+    pub fn get(&self, group: usize) -> Option<&[u8]> {
+        self.groups.get(group).and_then(|capture| capture.as_ref()).map(|capture| &capture[..])
+    }
+}
+
+/// The struct `Expect` drives a program through its master's pty:
+/// it sends input, and waits until the output matches a pattern.
+/// The output not yet matched is kept in a rolling buffer.
+#[derive(Debug)]
+pub struct Expect {
+    master: Master,
+    buffer: Vec<u8>,
+    buffer_size: usize,
+    timeout: Option<Duration>,
+    eof: bool,
+}
+
+impl Expect {
+    /// The constructor function `new` drives the master's pty, with
+    /// the default timeout and buffer size.
+    pub fn new(master: Master) -> Self {
+        Expect {
+            master,
+            buffer: Vec::new(),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            timeout: Some(DEFAULT_TIMEOUT),
+            eof: false,
+        }
+    }
+
+    /// Waits during at most `timeout` for each expectation, or
+    /// forever without timeout.
+    pub fn timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Keeps at most `size` bytes of unmatched output, dropping the
+    /// oldest ones.
+    pub fn buffer_size(&mut self, size: usize) -> &mut Self {
+        self.buffer_size = size;
+        self
+    }
+
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        &self.master
+    }
+
+    /// The function `into_inner` returns the master's pty, the
+    /// unmatched output is lost.
+    pub fn into_inner(self) -> Master {
+        self.master
+    }
+
+    /// The accessor function `buffer` returns the output read but
+    /// not matched yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Writes the bytes to the program, waiting until the pty can
+    /// be written when it's in the nonblocking mode.
+    pub fn send<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<()> {
+        let mut bytes = bytes.as_ref();
+
+        while !bytes.is_empty() {
+            match (&self.master).write(bytes) {
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    poll::poll(&mut [PollFd::new(&self.master, Interest::Writable)], None)
+                        .map_err(ExpectError::PollFail)?;
+                }
+                Err(err) => return Err(ExpectError::WriteFail(err)),
+                Ok(0) => return Err(ExpectError::WriteFail(io::ErrorKind::WriteZero.into())),
+                Ok(len) => bytes = &bytes[len..],
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the line to the program, followed by a newline.
+    pub fn send_line(&mut self, line: &str) -> Result<()> {
+        let mut bytes = Vec::with_capacity(line.len() + 1);
+
+        bytes.extend_from_slice(line.as_bytes());
+        bytes.push(b'\n');
+        self.send(bytes)
+    }
+
+    /// Writes the control character typed with the key, like `^C`
+    /// for `'c'`, which the pty may turn into a signal.
+    pub fn send_control(&mut self, key: char) -> Result<()> {
+        let code = match key {
+            'a'..='z' | 'A'..='Z' | '@' | '[' | '\\' | ']' | '^' | '_' => key as u8 & 0x1f,
+            '?' => 0x7f,
+            _ => return Err(ExpectError::BadControl(key)),
+        };
+
+        self.send([code])
+    }
+
+    /// Waits until the output matches the pattern, and consumes
+    /// the output up to the end of the match.
+    pub fn expect<P: Into<Pattern>>(&mut self, pattern: P) -> Result<Match> {
+        self.expect_any(&[pattern.into()])
+    }
+
+    /// Waits until the output matches one of the patterns, tried in
+    /// order, and consumes the output up to the end of the match.
+    /// `Eof` and `Timeout` consume the whole buffer.
+    pub fn expect_any(&mut self, patterns: &[Pattern]) -> Result<Match> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut chunk = [0; CHUNK];
+
+        loop {
+            if let Some(found) = self.find(patterns) {
+                return Ok(found);
+            }
+            if self.buffer.len() > self.buffer_size {
+                let excess = self.buffer.len() - self.buffer_size;
+
+                self.buffer.drain(..excess);
+            }
+            if self.eof {
+                return match patterns.iter().position(|pattern| matches!(*pattern, Pattern::Eof)) {
+                    None => Err(ExpectError::Eof { transcript: self.buffer.clone() }),
+                    Some(index) => Ok(self.consume(index)),
+                };
+            }
+
+            let left = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+
+            if left == Some(Duration::ZERO) {
+                return match patterns.iter().position(|pattern| matches!(*pattern, Pattern::Timeout)) {
+                    None => Err(ExpectError::Timeout { transcript: self.buffer.clone() }),
+                    Some(index) => Ok(self.consume(index)),
+                };
+            }
+            if poll::poll(&mut [PollFd::new(&self.master, Interest::Readable)], left)
+                .map_err(ExpectError::PollFail)? == 0 {
+                continue;
+            }
+            match (&self.master).read(&mut chunk) {
+                Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                Err(err) => return Err(ExpectError::ReadFail(err)),
+                Ok(0) => self.eof = true,
+                Ok(len) => self.buffer.extend_from_slice(&chunk[..len]),
+            }
+        }
+    }
+
+    /// The function `find` consumes the first match of the patterns
+    /// in the buffer.
+    fn find(&mut self, patterns: &[Pattern]) -> Option<Match> {
+        for (index, pattern) in patterns.iter().enumerate() {
+            let found = match *pattern {
+                Pattern::Exact(ref text) => {
+                    position(&self.buffer, text).map(|start| {
+                        (start, start + text.len(), vec![Some(text.clone())])
+                    })
+                }
+                #[cfg(feature = "regex")]
+                Pattern::Regex(ref regex) => {
+                    regex.captures(&self.buffer).map(|captures| {
+                        let whole = captures.get(0).expect("the group 0 always participates");
+                        let groups = captures.iter()
+                            .map(|capture| capture.map(|capture| capture.as_bytes().to_vec()))
+                            .collect();
+
+                        (whole.start(), whole.end(), groups)
+                    })
+                }
+                Pattern::Eof | Pattern::Timeout => None,
+            };
+
+            if let Some((start, end, groups)) = found {
+                let before = self.buffer[..start].to_vec();
+
+                self.buffer.drain(..end);
+                return Some(Match { index, before, groups });
+            }
+        }
+        None
+    }
+
+    /// The function `consume` empties the buffer for the pattern
+    /// `Eof` or `Timeout`.
+    fn consume(&mut self, index: usize) -> Match {
+        Match {
+            index,
+            before: mem::take(&mut self.buffer),
+            groups: vec![Some(Vec::new())],
+        }
+    }
+}
+
+/// The function `position` returns where the text first appears.
+fn position(haystack: &[u8], text: &[u8]) -> Option<usize> {
+    if text.is_empty() {
+        Some(0)
+    } else {
+        haystack.windows(text.len()).position(|window| window == text)
+    }
+}
@@ -1,76 +1,81 @@
-use ::descriptor::DescriptorError;
 use std::error::Error;
 use std::fmt;
+use std::io;
 
-use super::pty::{MasterError, SlaveError};
+use super::pty::MasterError;
+use super::SpawnError;
 
 /// The alias `Result` learns `ForkError` possibility.
-
 pub type Result<T> = ::std::result::Result<T, ForkError>;
 
 /// The enum `ForkError` defines the possible errors from constructor Fork.
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum ForkError {
     /// Can't creates the child.
-    Failure,
-    /// Can't set the id group.
-    SetsidFail,
+    Failure(io::Error),
     /// Can't suspending the calling process.
-    WaitpidFail,
+    WaitpidFail(io::Error),
+    /// Can't send the signal.
+    KillFail(io::Error),
     /// Is child and not parent.
     IsChild,
     /// Is parent and not child.
     IsParent,
     /// The Master occured a error.
     BadMaster(MasterError),
-    /// The Slave occured a error.
-    BadSlave(SlaveError),
-    /// The Master's Descriptor occured a error.
-    BadDescriptorMaster(DescriptorError),
-    /// The Slave's Descriptor occured a error.
-    BadDescriptorSlave(DescriptorError),
+    /// The child's setup failed.
+    Spawn(SpawnError),
 }
 
 impl fmt::Display for ForkError {
     /// The function `fmt` formats the value using the given formatter.
-
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", ::errno::errno())
+        match *self {
+            ForkError::Failure(ref err) => write!(f, "fork: {}", err),
+            ForkError::WaitpidFail(ref err) => write!(f, "waitpid: {}", err),
+            ForkError::KillFail(ref err) => write!(f, "kill: {}", err),
+            ForkError::BadMaster(ref err) => write!(f, "master: {}", err),
+            ForkError::Spawn(ref err) => write!(f, "child: {}", err),
+            ForkError::IsChild => write!(f, "is child and not parent"),
+            ForkError::IsParent => write!(f, "is parent and not child"),
+        }
     }
 }
 
 impl Error for ForkError {
     /// The function `description` returns a short description of the error.
-
     fn description(&self) -> &str {
         match *self {
-            ForkError::Failure => {
+            ForkError::Failure(_) => {
                 "On failure, -1 is returned in the parent,no child process is created, and errno \
                  isset appropriately."
             }
-            ForkError::SetsidFail => {
-                "fails if the calling process is alreadya process group leader."
-            }
-            ForkError::WaitpidFail => "Can't suspending the calling process.",
+            ForkError::WaitpidFail(_) => "Can't suspending the calling process.",
+            ForkError::KillFail(_) => "Can't send the signal.",
             ForkError::IsChild => "is child and not parent",
             ForkError::IsParent => "is parent and not child",
             ForkError::BadMaster(_) => "the master as occured an error",
-            ForkError::BadSlave(_) => "the slave as occured an error",
-            ForkError::BadDescriptorMaster(_) => "the master's descriptor as occured an error",
-            ForkError::BadDescriptorSlave(_) => "the slave's descriptor as occured an error",
+            ForkError::Spawn(_) => "the child can't be set up",
 
         }
     }
 
-    /// The function `cause` returns the lower-level cause of this error, if any.
-
-    fn cause(&self) -> Option<&Error> {
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
+            ForkError::Failure(ref err) => Some(err),
+            ForkError::WaitpidFail(ref err) => Some(err),
+            ForkError::KillFail(ref err) => Some(err),
             ForkError::BadMaster(ref err) => Some(err),
-            ForkError::BadSlave(ref err) => Some(err),
-            ForkError::BadDescriptorMaster(ref err) => Some(err),
-            ForkError::BadDescriptorSlave(ref err) => Some(err),
+            ForkError::Spawn(ref err) => Some(err),
             _ => None,
         }
     }
 }
+
+impl From<ForkError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: ForkError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -1,18 +1,35 @@
 mod pty;
 mod err;
+mod notifier;
+mod process;
+pub(crate) mod shutdown;
+pub(crate) mod signal;
+pub(crate) mod spawn;
+pub(crate) mod status;
 
-use ::descriptor::Descriptor;
 
 use ::libc;
 pub use self::err::{ForkError, Result};
-pub use self::pty::{Master, MasterError};
+pub use self::pty::{Master, MasterError, MasterReader, MasterWriter};
 pub use self::pty::{Slave, SlaveError};
-use std::ffi::CString;
+pub use self::pty::{Termios, WindowSize};
+pub use self::notifier::ExitNotifier;
+pub use self::process::Process;
+pub use self::shutdown::ShutdownPolicy;
+pub use self::signal::Signal;
+pub use self::spawn::SpawnError;
+use self::spawn::Forked;
+pub use self::status::ExitStatus;
+use std::ffi::{CStr, CString};
+use std::io;
+use std::os::unix::ffi::OsStringExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum Fork {
-    // Parent child's pid and master's pty.
-    Parent(libc::pid_t, Master),
+    // Parent child's process and master's pty.
+    Parent(Process, Master),
     // Child pid 0.
     Child(Slave),
 }
@@ -21,51 +38,36 @@ impl Fork {
     /// The constructor function `new` forks the program
     /// and returns the current pid.
     pub fn new(path: &'static str) -> Result<Self> {
-        match Master::new(CString::new(path).ok().unwrap_or_default().as_ptr()) {
-            Err(cause) => Err(ForkError::BadMaster(cause)),
-            Ok(master) => unsafe {
-                if let Some(cause) = master.grantpt().err().or(master.unlockpt().err()) {
-                    Err(ForkError::BadMaster(cause))
-                } else {
-                    match libc::fork() {
-                        -1 => Err(ForkError::Failure),
-                        0 => {
-                            match master.ptsname() {
-                                Err(cause) => Err(ForkError::BadMaster(cause)),
-                                Ok(name) => Fork::from_pts(name), 
-                            }
-                        }
-                        pid => Ok(Fork::Parent(pid, master)),
-                    }
-                }
-            },
+        Fork::new_with(path, None, true)
+    }
+
+    /// The constructor function `with_window_size` forks the program
+    /// like `new` with a pty already resized to `size`.
+    pub fn with_window_size(path: &'static str, size: WindowSize) -> Result<Self> {
+        Fork::new_with(path, Some(size), true)
+    }
+
+    /// The constructor function `new_with` forks the program like
+    /// `new`, with a pty resized to `size` if any. The slave becomes
+    /// the controlling terminal of the child only if `controlling`,
+    /// like with `Command::controlling_terminal`.
+    pub fn new_with(path: &'static str, size: Option<WindowSize>, controlling: bool) -> Result<Self> {
+        let master = unlocked_master(path, size, None).map_err(ForkError::BadMaster)?;
+        let ptsname = slave_name(&master).map_err(ForkError::BadMaster)?;
+
+        match unsafe { spawn::fork(|| Fork::from_pts(&master, &ptsname, controlling)) } {
+            Err(err) => Err(ForkError::Failure(err)),
+            Ok(Err(cause)) => Err(ForkError::Spawn(cause)),
+            Ok(Ok(Forked::Parent(pid))) => Ok(Fork::Parent(Process::new(pid), master)),
+            Ok(Ok(Forked::Child(fork))) => Ok(fork),
         }
     }
 
     /// The constructor function `from_pts` is a private
     /// extention from the constructor function `new` who
     /// prepares and returns the child.
-    fn from_pts(ptsname: *const ::libc::c_char) -> Result<Self> {
-        unsafe {
-            if libc::setsid() == -1 {
-                Err(ForkError::SetsidFail)
-            } else {
-                match Slave::new(ptsname) {
-                    Err(cause) => Err(ForkError::BadSlave(cause)),
-                    Ok(slave) => {
-                        if let Some(cause) = slave.dup2(libc::STDIN_FILENO)
-                            .err()
-                            .or(slave.dup2(libc::STDOUT_FILENO)
-                                .err()
-                                .or(slave.dup2(libc::STDERR_FILENO).err())) {
-                            Err(ForkError::BadSlave(cause))
-                        } else {
-                            Ok(Fork::Child(slave))
-                        }
-                    }
-                }
-            }
-        }
+    fn from_pts(master: &Master, ptsname: &CStr, controlling: bool) -> ::std::result::Result<Self, SpawnError> {
+        attach_slave(master.as_raw_fd(), ptsname, controlling).map(Fork::Child)
     }
 
     /// The constructor function `from_ptmx` forks the program
@@ -74,30 +76,63 @@ impl Fork {
         Fork::new(::DEFAULT_PTMX)
     }
 
-    /// Waits until it's terminated.
-    pub fn wait(&self) -> Result<libc::pid_t> {
+    /// Waits for the child, see `Process::wait`.
+    pub fn wait(&self) -> Result<ExitStatus> {
+        self.process()?.wait().map_err(ForkError::WaitpidFail)
+    }
+
+    /// Checks on the child without blocking, see `Process::try_wait`.
+    pub fn try_wait(&self) -> Result<Option<ExitStatus>> {
+        self.process()?.try_wait().map_err(ForkError::WaitpidFail)
+    }
+
+    /// Waits for the child during at most `timeout`, see
+    /// `Process::wait_timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<ExitStatus>> {
+        self.process()?.wait_timeout(timeout).map_err(ForkError::WaitpidFail)
+    }
+
+    /// Stops the child, see `Process::shutdown`.
+    pub fn shutdown(&self, policy: ShutdownPolicy) -> Result<ExitStatus> {
+        self.process()?.shutdown(policy).map_err(ForkError::WaitpidFail)
+    }
+
+    /// Signals the child, see `Process::kill`.
+    pub fn kill(&self, signal: Signal) -> Result<()> {
+        self.process()?.kill(signal).map_err(ForkError::KillFail)
+    }
+
+    /// Signals the child's process group, see `Process::kill_group`.
+    pub fn kill_group(&self, signal: Signal) -> Result<()> {
+        self.process()?.kill_group(signal).map_err(ForkError::KillFail)
+    }
+
+    /// Signals the foreground job of the pty, see
+    /// `Process::kill_foreground`.
+    pub fn kill_foreground(&self, signal: Signal) -> Result<()> {
         match *self {
             Fork::Child(_) => Err(ForkError::IsChild),
-            Fork::Parent(pid, _) => {
-                loop {
-                    unsafe {
-                        match libc::waitpid(pid, &mut 0, 0) {
-                            0 => continue,
-                            -1 => return Err(ForkError::WaitpidFail),
-                            _ => return Ok(pid),
-                        }
-                    }
-                }
+            Fork::Parent(ref process, ref master) => {
+                process.kill_foreground(master, signal).map_err(ForkError::KillFail)
             }
         }
     }
 
+    /// The accessor function `process` returns the parent's handle
+    /// on the child.
+    pub fn process(&self) -> Result<&Process> {
+        match *self {
+            Fork::Child(_) => Err(ForkError::IsChild),
+            Fork::Parent(ref process, _) => Ok(process),
+        }
+    }
+
     /// The function `is_parent` returns the pid or parent
     /// or none.
-    pub fn is_parent(&self) -> Result<Master> {
+    pub fn is_parent(&self) -> Result<&Master> {
         match *self {
             Fork::Child(_) => Err(ForkError::IsChild),
-            Fork::Parent(_, ref master) => Ok(master.clone()),
+            Fork::Parent(_, ref master) => Ok(master),
         }
     }
 
@@ -105,17 +140,72 @@ impl Fork {
     /// or none.
     pub fn is_child(&self) -> Result<&Slave> {
         match *self {
-            Fork::Parent(_, _) => Err(ForkError::IsParent),
+            Fork::Parent(..) => Err(ForkError::IsParent),
             Fork::Child(ref slave) => Ok(slave),
         }
     }
 }
 
-impl Drop for Fork {
-    fn drop(&mut self) {
-        match *self {
-            Fork::Parent(_, ref master) => Descriptor::drop(master),
-            _ => {}
+/// The function `unlocked_master` opens the PTMX's path, resizes and
+/// configures the pty, and unlocks the slave, ready to be opened by a
+/// child. The pty is set up before the child exists, so that nothing
+/// written early is processed with the default configuration.
+pub(crate) fn unlocked_master(path: &str,
+                              size: Option<WindowSize>,
+                              termios: Option<fn(Termios) -> Termios>)
+                              -> ::std::result::Result<Master, MasterError> {
+    let master = Master::new(path)?;
+
+    if let Some(cause) = master.grantpt()
+        .err()
+        .or(master.unlockpt().err())
+        .or(size.and_then(|size| master.set_window_size(size).err()))
+        .or(termios.and_then(|preset| {
+            master.termios().and_then(|termios| master.set_termios(&preset(termios))).err()
+        })) {
+        Err(cause)
+    } else {
+        Ok(master)
+    }
+}
+
+/// The function `slave_name` returns the path of the slave as
+/// expected by `attach_slave`, in case `TIOCGPTPEER` is missing.
+pub(crate) fn slave_name(master: &Master) -> ::std::result::Result<CString, MasterError> {
+    let path = master.slave_path().map_err(MasterError::PtsnameError)?;
+
+    CString::new(path.into_os_string().into_vec())
+        .map_err(|err| MasterError::PtsnameError(err.into()))
+}
+
+/// The function `attach_slave` starts a new session in the
+/// current process, makes the slave its controlling terminal if
+/// `controlling`, and plugs the slave on its standard streams.
+/// It only does async-signal-safe calls and so can run in a
+/// child freshly forked from a multi-threaded parent.
+pub(crate) fn attach_slave(master: RawFd,
+                           ptsname: &CStr,
+                           controlling: bool)
+                           -> ::std::result::Result<Slave, SpawnError> {
+    let errno = || io::Error::last_os_error().raw_os_error().unwrap_or(0);
+
+    unsafe {
+        if libc::setsid() == -1 {
+            return Err(SpawnError::Setsid { errno: errno() });
+        }
+    }
+    match Slave::from_peer(master, ptsname) {
+        Err(_) => Err(SpawnError::OpenSlave { errno: errno() }),
+        Ok(slave) => {
+            if controlling && slave.set_controlling_terminal().is_err() {
+                return Err(SpawnError::ControllingTerminal { errno: errno() });
+            }
+            for &fd in &[libc::STDIN_FILENO, libc::STDOUT_FILENO, libc::STDERR_FILENO] {
+                if slave.dup2(fd).is_err() {
+                    return Err(SpawnError::Dup2 { errno: errno() });
+                }
+            }
+            Ok(slave)
         }
     }
 }
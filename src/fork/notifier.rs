@@ -0,0 +1,85 @@
+use ::libc;
+use ::sigpipe::SignalPipe;
+
+use std::io;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+
+use super::process::Process;
+use super::status::ExitStatus;
+
+/// The struct `ExitNotifier` is a fd which becomes readable each
+/// time the process receives a `SIGCHLD`, to wait for a child from
+/// an event loop instead of blocking in `waitpid`(2). The status is
+/// kept by the child's `Process`.
+#[derive(Debug)]
+pub struct ExitNotifier<'a> {
+    process: &'a Process,
+    pipe: SignalPipe,
+}
+
+impl<'a> ExitNotifier<'a> {
+    /// The constructor function `new` listens to `SIGCHLD` for the
+    /// child's process, like `Child::process`. Create it before
+    /// checking the child the first time, so that no exit is missed.
+    pub fn new(process: &'a Process) -> io::Result<Self> {
+        Ok(ExitNotifier {
+            process,
+            pipe: SignalPipe::new(libc::SIGCHLD)?,
+        })
+    }
+
+    /// The accessor function `pid` returns the child's pid.
+    pub fn pid(&self) -> libc::pid_t {
+        self.process.pid()
+    }
+
+    /// The function `drain` consumes the notifications received so far.
+    pub(crate) fn drain(&self) {
+        self.pipe.drain();
+    }
+
+    /// Consumes the notifications received so far and returns
+    /// the exit status if the child is terminated.
+    pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
+        self.drain();
+        self.process.try_wait()
+    }
+}
+
+impl<'a> AsRawFd for ExitNotifier<'a> {
+    /// The accessor function `as_raw_fd` returns the fd to watch.
+    fn as_raw_fd(&self) -> RawFd {
+        self.pipe.as_raw_fd()
+    }
+}
+
+impl<'a> AsFd for ExitNotifier<'a> {
+    /// The accessor function `as_fd` borrows the fd to watch.
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // The pipe is closed when the notifier is dropped.
+        unsafe { BorrowedFd::borrow_raw(self.pipe.as_raw_fd()) }
+    }
+}
+
+#[cfg(feature = "mio")]
+impl<'a> ::mio::event::Source for ExitNotifier<'a> {
+    fn register(&mut self,
+                registry: &::mio::Registry,
+                token: ::mio::Token,
+                interests: ::mio::Interest)
+                -> io::Result<()> {
+        ::mio::unix::SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self,
+                  registry: &::mio::Registry,
+                  token: ::mio::Token,
+                  interests: ::mio::Interest)
+                  -> io::Result<()> {
+        ::mio::unix::SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &::mio::Registry) -> io::Result<()> {
+        ::mio::unix::SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
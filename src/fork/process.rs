@@ -0,0 +1,102 @@
+use ::libc;
+
+use std::cell::Cell;
+use std::io;
+use std::time::Duration;
+
+use super::pty::Master;
+use super::shutdown::{self, ShutdownPolicy};
+use super::signal::{self, Signal};
+use super::status::{self, ExitStatus};
+
+/// The struct `Process` is the parent's handle on a child: its pid,
+/// and its exit status once reaped. By then the pid may belong to
+/// another process, so the child is never waited for nor signaled
+/// again.
+#[derive(Debug)]
+pub struct Process {
+    pid: libc::pid_t,
+    status: Cell<Option<ExitStatus>>,
+}
+
+impl Process {
+    /// The constructor function `new` returns the handle on the
+    /// child `pid`, which isn't reaped yet.
+    pub(crate) fn new(pid: libc::pid_t) -> Self {
+        Process {
+            pid,
+            status: Cell::new(None),
+        }
+    }
+
+    /// The accessor function `pid` returns the child's pid.
+    pub fn pid(&self) -> libc::pid_t {
+        self.pid
+    }
+
+    /// Waits until it's terminated and returns its exit status,
+    /// which is kept for the next calls.
+    pub fn wait(&self) -> io::Result<ExitStatus> {
+        if let Some(status) = self.status.get() {
+            return Ok(status);
+        }
+
+        let status = status::wait(self.pid)?;
+
+        self.status.set(Some(status));
+        Ok(status)
+    }
+
+    /// Returns the exit status if it's terminated, without blocking.
+    pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
+        if self.status.get().is_none() {
+            self.status.set(status::try_wait(self.pid)?);
+        }
+        Ok(self.status.get())
+    }
+
+    /// Waits during at most `timeout` and returns the exit status
+    /// if it's terminated meanwhile.
+    pub fn wait_timeout(&self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        if self.status.get().is_none() {
+            self.status.set(status::wait_timeout(self.pid, timeout)?);
+        }
+        Ok(self.status.get())
+    }
+
+    /// Hangs up, then terminates, then kills the child and its
+    /// process group, waiting for the grace periods of the policy
+    /// in between, and returns its exit status once reaped.
+    pub fn shutdown(&self, policy: ShutdownPolicy) -> io::Result<ExitStatus> {
+        shutdown::shutdown(self, policy)
+    }
+
+    /// Sends the signal to the child. Nothing is sent once the
+    /// child is reaped.
+    pub fn kill(&self, signal: Signal) -> io::Result<()> {
+        if self.status.get().is_some() {
+            return Ok(());
+        }
+        signal::kill(self.pid, signal)
+    }
+
+    /// Sends the signal to the process group of the child, which
+    /// leads its own session. Nothing is sent once the child is
+    /// reaped.
+    pub fn kill_group(&self, signal: Signal) -> io::Result<()> {
+        if self.status.get().is_some() {
+            return Ok(());
+        }
+        signal::killpg(self.pid, signal)
+    }
+
+    /// Sends the signal to the foreground process group of the pty
+    /// `master`, like the terminal does when `^C` is typed. Nothing
+    /// is sent once the child is reaped.
+    pub fn kill_foreground(&self, master: &Master, signal: Signal) -> io::Result<()> {
+        if self.status.get().is_some() {
+            return Ok(());
+        }
+        signal::killpg(master.foreground_process_group()?, signal)
+    }
+}
@@ -1,47 +1,78 @@
 use ::descriptor::DescriptorError;
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 /// The alias `Result` learns `MasterError` possibility.
-
 pub type Result<T> = ::std::result::Result<T, MasterError>;
 
 /// The enum `MasterError` defines the possible errors from constructor Master.
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum MasterError {
     BadDescriptor(DescriptorError),
-    GrantptError,
-    UnlockptError,
-    PtsnameError,
+    GrantptError(io::Error),
+    UnlockptError(io::Error),
+    PtsnameError(io::Error),
+    WindowSizeError(io::Error),
+    TermiosError(io::Error),
+    TcgetpgrpError(io::Error),
+    FcntlError(io::Error),
+    PollError(io::Error),
 }
 
 impl fmt::Display for MasterError {
     /// The function `fmt` formats the value using the given formatter.
-
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", ::errno::errno())
+        match *self {
+            MasterError::GrantptError(ref err) => write!(f, "grantpt: {}", err),
+            MasterError::UnlockptError(ref err) => write!(f, "unlockpt: {}", err),
+            MasterError::PtsnameError(ref err) => write!(f, "ptsname: {}", err),
+            MasterError::WindowSizeError(ref err) => write!(f, "window size: {}", err),
+            MasterError::TermiosError(ref err) => write!(f, "termios: {}", err),
+            MasterError::TcgetpgrpError(ref err) => write!(f, "tcgetpgrp: {}", err),
+            MasterError::FcntlError(ref err) => write!(f, "fcntl: {}", err),
+            MasterError::PollError(ref err) => write!(f, "poll: {}", err),
+            MasterError::BadDescriptor(ref err) => write!(f, "descriptor: {}", err),
+        }
     }
 }
 
 impl Error for MasterError {
     /// The function `description` returns a short description of the error.
-
     fn description(&self) -> &str {
         match *self {
             MasterError::BadDescriptor(_) => "the descriptor as occured an error",
-            MasterError::GrantptError => "the `grantpt` has a error, errnois set appropriately.",
-            MasterError::UnlockptError => "the `grantpt` has a error, errnois set appropriately.",
-            MasterError::PtsnameError => "the `ptsname` has a error",
+            MasterError::GrantptError(_) => "the `grantpt` has a error, errnois set appropriately.",
+            MasterError::UnlockptError(_) => "the `grantpt` has a error, errnois set appropriately.",
+            MasterError::PtsnameError(_) => "the `ptsname_r` has a error",
+            MasterError::WindowSizeError(_) => "the `TIOCGWINSZ` or `TIOCSWINSZ` has a error",
+            MasterError::TermiosError(_) => "the `tcgetattr` or `tcsetattr` has a error",
+            MasterError::TcgetpgrpError(_) => "the `tcgetpgrp` has a error",
+            MasterError::FcntlError(_) => "the `fcntl` has a error",
+            MasterError::PollError(_) => "the `poll` has a error",
 
         }
     }
 
-    /// The function `cause` returns the lower-level cause of this error, if any.
-
-    fn cause(&self) -> Option<&Error> {
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
+            MasterError::GrantptError(ref err) => Some(err),
+            MasterError::UnlockptError(ref err) => Some(err),
+            MasterError::PtsnameError(ref err) => Some(err),
+            MasterError::WindowSizeError(ref err) => Some(err),
+            MasterError::TermiosError(ref err) => Some(err),
+            MasterError::TcgetpgrpError(ref err) => Some(err),
+            MasterError::FcntlError(ref err) => Some(err),
+            MasterError::PollError(ref err) => Some(err),
             MasterError::BadDescriptor(ref err) => Some(err),
-            _ => None,
         }
     }
 }
+
+impl From<MasterError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: MasterError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -1,32 +1,62 @@
 mod err;
+mod split;
 
 use libc;
 
-use ::descriptor::Descriptor;
+use ::descriptor::{self, Descriptor, DescriptorError};
+use ::poll::{self, Interest, PollFd};
+
+use super::{Termios, WindowSize};
 
 pub use self::err::{MasterError, Result};
+pub use self::split::{MasterReader, MasterWriter};
+use std::ffi::{CStr, CString, OsStr};
 use std::io;
-use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+use std::sync::Mutex;
+use std::time::Duration;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 
-#[derive(Debug, Copy, Clone)]
+/// The struct `Master` owns the master's pty, closed once when
+/// it's dropped. Use `try_clone` for another handle on the pty.
+#[derive(Debug)]
 pub struct Master {
-    pty: RawFd,
+    pty: OwnedFd,
 }
 
 impl Master {
-    pub fn new(path: *const ::libc::c_char) -> Result<Self> {
-        match Self::open(path, libc::O_RDWR, None) {
+    /// The constructor function `new` opens the PTMX's path.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = CString::new(path.as_ref().as_os_str().as_bytes())
+            .map_err(|err| MasterError::BadDescriptor(DescriptorError::OpenFail(err.into())))?;
+
+        match descriptor::open(&path, libc::O_RDWR, None) {
             Err(cause) => Err(MasterError::BadDescriptor(cause)),
-            Ok(fd) => Ok(Master { pty: fd }),
+            Ok(fd) => Ok(unsafe { Master::from_raw_fd(fd) }),
         }
     }
 
+    /// Returns another handle on the pty, from a duplicate of the
+    /// fd which is closed on exec. Both handles share the open file
+    /// description, and so the nonblocking mode.
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.pty.try_clone().map(Master::from)
+    }
+
+    /// Splits the master into a reader and a writer, which can be
+    /// used from distinct threads, sharing the same pty.
+    pub fn split(self) -> (MasterReader, MasterWriter) {
+        split::split(self)
+    }
+
     /// Change UID and GID of slave pty associated with master pty whose
     /// fd is provided, to the real UID and real GID of the calling thread.
     pub fn grantpt(&self) -> Result<libc::c_int> {
         unsafe {
             match libc::grantpt(self.as_raw_fd()) {
-                -1 => Err(MasterError::GrantptError),
+                -1 => Err(MasterError::GrantptError(io::Error::last_os_error())),
                 c => Ok(c),
             }
         }
@@ -36,22 +66,128 @@ impl Master {
     pub fn unlockpt(&self) -> Result<libc::c_int> {
         unsafe {
             match libc::unlockpt(self.as_raw_fd()) {
-                -1 => Err(MasterError::UnlockptError),
+                -1 => Err(MasterError::UnlockptError(io::Error::last_os_error())),
                 c => Ok(c),
             }
         }
     }
 
-    /// Returns a pointer to a static buffer, which will be overwritten on
-    /// subsequent calls.
-    pub fn ptsname(&self) -> Result<*const libc::c_char> {
+    /// Returns the path of the slave pty, like `/dev/pts/3`.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn slave_path(&self) -> io::Result<PathBuf> {
+        let mut buf: Vec<libc::c_char> = vec![0; 64];
+
+        loop {
+            match unsafe { libc::ptsname_r(self.as_raw_fd(), buf.as_mut_ptr(), buf.len()) } {
+                0 => {
+                    let name = unsafe { CStr::from_ptr(buf.as_ptr()) };
+
+                    return Ok(PathBuf::from(OsStr::from_bytes(name.to_bytes())));
+                }
+                libc::ERANGE => {
+                    let len = buf.len() * 2;
+
+                    buf.resize(len, 0);
+                }
+                errno => return Err(io::Error::from_raw_os_error(errno)),
+            }
+        }
+    }
+
+    /// Returns the path of the slave pty, like `/dev/ttys003`.
+    /// Without `ptsname_r`, the static buffer of `ptsname` is
+    /// guarded by a lock shared by all the masters.
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn slave_path(&self) -> io::Result<PathBuf> {
+        static PTSNAME: Mutex<()> = Mutex::new(());
+        let _guard = PTSNAME.lock().unwrap_or_else(|e| e.into_inner());
+
         unsafe {
             match libc::ptsname(self.as_raw_fd()) {
-                c if c.is_null() => Err(MasterError::PtsnameError),
-                c => Ok(c),
+                name if name.is_null() => Err(io::Error::last_os_error()),
+                name => Ok(PathBuf::from(OsStr::from_bytes(CStr::from_ptr(name).to_bytes()))),
+            }
+        }
+    }
+
+    /// Returns the window size of the pty.
+    pub fn window_size(&self) -> Result<WindowSize> {
+        WindowSize::from_fd(self.as_raw_fd()).map_err(MasterError::WindowSizeError)
+    }
+
+    /// Resizes the pty, the foreground process group of the
+    /// slave receives a `SIGWINCH`.
+    pub fn set_window_size(&self, size: WindowSize) -> Result<()> {
+        size.apply(self.as_raw_fd()).map_err(MasterError::WindowSizeError)
+    }
+
+    /// Switches the nonblocking mode: reads and writes which would
+    /// block fail with `io::ErrorKind::WouldBlock` instead. The mode
+    /// belongs to the open file description, it also applies to the
+    /// handles returned by `try_clone`, like the one kept by `Child`.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> Result<()> {
+        unsafe {
+            match libc::fcntl(self.as_raw_fd(), libc::F_GETFL) {
+                -1 => Err(MasterError::FcntlError(io::Error::last_os_error())),
+                flags => {
+                    let flags = if nonblocking {
+                        flags | libc::O_NONBLOCK
+                    } else {
+                        flags & !libc::O_NONBLOCK
+                    };
+
+                    match libc::fcntl(self.as_raw_fd(), libc::F_SETFL, flags) {
+                        -1 => Err(MasterError::FcntlError(io::Error::last_os_error())),
+                        _ => Ok(()),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Waits during at most `timeout`, or forever without timeout,
+    /// until the pty can be read, and returns whether it can. A hung
+    /// up pty can be read: the read reports the end of file.
+    pub fn wait_readable(&self, timeout: Option<Duration>) -> Result<bool> {
+        self.wait(Interest::Readable, timeout)
+            .map(|readiness| readiness.is_readable() || readiness.is_hangup())
+    }
+
+    /// Waits during at most `timeout`, or forever without timeout,
+    /// until the pty can be written, and returns whether it can.
+    pub fn wait_writable(&self, timeout: Option<Duration>) -> Result<bool> {
+        self.wait(Interest::Writable, timeout).map(|readiness| readiness.is_writable())
+    }
+
+    /// The function `wait` polls the pty alone.
+    fn wait(&self, interest: Interest, timeout: Option<Duration>) -> Result<poll::Readiness> {
+        let mut fds = [PollFd::new(self, interest)];
+
+        match poll::poll(&mut fds, timeout) {
+            Err(err) => Err(MasterError::PollError(err)),
+            Ok(_) => Ok(fds[0].readiness()),
+        }
+    }
+
+    /// Returns the foreground process group of the pty.
+    pub fn foreground_process_group(&self) -> Result<libc::pid_t> {
+        unsafe {
+            match libc::tcgetpgrp(self.as_raw_fd()) {
+                -1 => Err(MasterError::TcgetpgrpError(io::Error::last_os_error())),
+                pgrp => Ok(pgrp),
             }
         }
     }
+
+    /// Returns the line discipline's configuration of the pty.
+    pub fn termios(&self) -> Result<Termios> {
+        Termios::from_fd(self.as_raw_fd()).map_err(MasterError::TermiosError)
+    }
+
+    /// Configures the line discipline of the pty right away.
+    pub fn set_termios(&self, termios: &Termios) -> Result<()> {
+        termios.apply(self.as_raw_fd()).map_err(MasterError::TermiosError)
+    }
 }
 
 impl Descriptor for Master {}
@@ -59,24 +195,119 @@ impl Descriptor for Master {}
 impl AsRawFd for Master {
     /// The accessor function `as_raw_fd` returns the fd.
     fn as_raw_fd(&self) -> RawFd {
-        self.pty
+        self.pty.as_raw_fd()
+    }
+}
+
+impl AsFd for Master {
+    /// The accessor function `as_fd` borrows the fd.
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.pty.as_fd()
+    }
+}
+
+impl IntoRawFd for Master {
+    /// The function `into_raw_fd` gives up the ownership of the fd.
+    fn into_raw_fd(self) -> RawFd {
+        self.pty.into_raw_fd()
+    }
+}
+
+impl FromRawFd for Master {
+    /// The constructor function `from_raw_fd` takes the ownership
+    /// of fd, which must be an open master's pty.
+    unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Master { pty: OwnedFd::from_raw_fd(fd) }
+    }
+}
+
+impl From<OwnedFd> for Master {
+    fn from(pty: OwnedFd) -> Self {
+        Master { pty }
+    }
+}
+
+impl From<Master> for OwnedFd {
+    fn from(master: Master) -> Self {
+        master.pty
+    }
+}
+
+/// The master's pty is only closed when the `Master` is dropped,
+/// never by `Read` or `Write`, so `Async<Master>` may hand out
+/// `&mut Master`.
+#[cfg(feature = "async-io")]
+unsafe impl ::async_io::IoSafe for Master {}
+
+#[cfg(feature = "mio")]
+impl ::mio::event::Source for Master {
+    /// Registers the pty, switched to the nonblocking mode along with
+    /// its clones, for edge-triggered readiness: read until `WouldBlock`.
+    fn register(&mut self,
+                registry: &::mio::Registry,
+                token: ::mio::Token,
+                interests: ::mio::Interest)
+                -> io::Result<()> {
+        self.set_nonblocking(true)?;
+        ::mio::unix::SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self,
+                  registry: &::mio::Registry,
+                  token: ::mio::Token,
+                  interests: ::mio::Interest)
+                  -> io::Result<()> {
+        ::mio::unix::SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &::mio::Registry) -> io::Result<()> {
+        ::mio::unix::SourceFd(&self.as_raw_fd()).deregister(registry)
     }
 }
 
 impl io::Read for Master {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        unsafe {
-            match libc::read(self.as_raw_fd(),
-                             buf.as_mut_ptr() as *mut libc::c_void,
-                             buf.len()) {
-                -1 => Ok(0),
-                len => Ok(len as usize),
+        (&*self).read(buf)
+    }
+}
+
+impl io::Write for Master {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+
+impl io::Read for &Master {
+    /// Reads from the pty, retrying on `EINTR`. The hangup of the
+    /// slave (`EIO` on Linux) is the end of file, and `EAGAIN` is
+    /// returned as `WouldBlock`.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            match unsafe {
+                libc::read(self.as_raw_fd(),
+                           buf.as_mut_ptr() as *mut libc::c_void,
+                           buf.len())
+            } {
+                -1 => {
+                    let err = io::Error::last_os_error();
+
+                    match err.raw_os_error() {
+                        Some(libc::EINTR) => continue,
+                        Some(libc::EIO) => return Ok(0),
+                        _ => return Err(err),
+                    }
+                }
+                len => return Ok(len as usize),
             }
         }
     }
 }
 
-impl io::Write for Master {
+impl io::Write for &Master {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         unsafe {
             match libc::write(self.as_raw_fd(),
@@ -0,0 +1,86 @@
+use super::Master;
+
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+use std::sync::Arc;
+
+/// The struct `MasterReader` is the reading half of a master's pty,
+/// returned by `Master::split`.
+#[derive(Debug)]
+pub struct MasterReader {
+    master: Arc<Master>,
+}
+
+/// The struct `MasterWriter` is the writing half of a master's pty,
+/// returned by `Master::split`.
+#[derive(Debug)]
+pub struct MasterWriter {
+    master: Arc<Master>,
+}
+
+/// The function `split` shares the master between both halves,
+/// the pty is closed once both are dropped.
+pub(super) fn split(master: Master) -> (MasterReader, MasterWriter) {
+    let master = Arc::new(master);
+
+    (MasterReader { master: master.clone() }, MasterWriter { master })
+}
+
+impl MasterReader {
+    /// The accessor function `get_ref` returns the master's pty.
+    pub fn get_ref(&self) -> &Master {
+        &self.master
+    }
+}
+
+impl MasterWriter {
+    /// The accessor function `get_ref` returns the master's pty,
+    /// to resize it for example.
+    pub fn get_ref(&self) -> &Master {
+        &self.master
+    }
+}
+
+impl Read for MasterReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self.master).read(buf)
+    }
+}
+
+impl Write for MasterWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self.master).write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self.master).flush()
+    }
+}
+
+impl AsRawFd for MasterReader {
+    /// The accessor function `as_raw_fd` returns the fd.
+    fn as_raw_fd(&self) -> RawFd {
+        self.master.as_raw_fd()
+    }
+}
+
+impl AsFd for MasterReader {
+    /// The accessor function `as_fd` borrows the fd.
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.master.as_fd()
+    }
+}
+
+impl AsRawFd for MasterWriter {
+    /// The accessor function `as_raw_fd` returns the fd.
+    fn as_raw_fd(&self) -> RawFd {
+        self.master.as_raw_fd()
+    }
+}
+
+impl AsFd for MasterWriter {
+    /// The accessor function `as_fd` borrows the fd.
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.master.as_fd()
+    }
+}
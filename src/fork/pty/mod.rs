@@ -1,5 +1,9 @@
 mod master;
 mod slave;
+mod termios;
+mod winsize;
 
-pub use self::master::{Master, MasterError};
+pub use self::master::{Master, MasterError, MasterReader, MasterWriter};
 pub use self::slave::{Slave, SlaveError};
+pub use self::termios::Termios;
+pub use self::winsize::WindowSize;
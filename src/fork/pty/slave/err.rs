@@ -1,42 +1,61 @@
 use ::descriptor::DescriptorError;
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 /// The alias `Result` learns `SlaveError` possibility.
-
 pub type Result<T> = ::std::result::Result<T, SlaveError>;
 
 /// The enum `SlaveError` defines the possible errors from constructor Slave.
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum SlaveError {
     BadDescriptor(DescriptorError),
-    Dup2Error,
+    Dup2Error(io::Error),
+    WindowSizeError(io::Error),
+    TermiosError(io::Error),
+    ControllingTerminalError(io::Error),
 }
 
 impl fmt::Display for SlaveError {
     /// The function `fmt` formats the value using the given formatter.
-
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", ::errno::errno())
+        match *self {
+            SlaveError::Dup2Error(ref err) => write!(f, "dup2: {}", err),
+            SlaveError::WindowSizeError(ref err) => write!(f, "window size: {}", err),
+            SlaveError::TermiosError(ref err) => write!(f, "termios: {}", err),
+            SlaveError::ControllingTerminalError(ref err) => write!(f, "TIOCSCTTY: {}", err),
+            SlaveError::BadDescriptor(ref err) => write!(f, "descriptor: {}", err),
+        }
     }
 }
 
 impl Error for SlaveError {
     /// The function `description` returns a short description of the error.
-
     fn description(&self) -> &str {
         match *self {
             SlaveError::BadDescriptor(_) => "the descriptor as occured an error",
-            SlaveError::Dup2Error => "the `dup2` has a error, errno isset appropriately.",
+            SlaveError::Dup2Error(_) => "the `dup2` has a error, errno isset appropriately.",
+            SlaveError::WindowSizeError(_) => "the `TIOCGWINSZ` or `TIOCSWINSZ` has a error",
+            SlaveError::TermiosError(_) => "the `tcgetattr` or `tcsetattr` has a error",
+            SlaveError::ControllingTerminalError(_) => "the `TIOCSCTTY` has a error",
         }
     }
 
-    /// The function `cause` returns the lower-level cause of this error, if any.
-
-    fn cause(&self) -> Option<&Error> {
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
+            SlaveError::Dup2Error(ref err) => Some(err),
+            SlaveError::WindowSizeError(ref err) => Some(err),
+            SlaveError::TermiosError(ref err) => Some(err),
+            SlaveError::ControllingTerminalError(ref err) => Some(err),
             SlaveError::BadDescriptor(ref err) => Some(err),
-            _ => None,
         }
     }
 }
+
+impl From<SlaveError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: SlaveError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -1,33 +1,142 @@
 mod err;
 
-use ::descriptor::Descriptor;
+use ::descriptor::{Descriptor, DescriptorError};
 use ::libc;
 
+use super::{Master, Termios, WindowSize};
+
 pub use self::err::{SlaveError, Result};
-use std::os::unix::io::{AsRawFd, RawFd};
+use std::ffi::{CStr, CString};
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 
+/// The struct `Slave` owns the slave's pty, closed once when
+/// it's dropped.
 #[derive(Debug)]
 pub struct Slave {
-    pty: RawFd,
+    pty: OwnedFd,
 }
 
 impl Slave {
     /// The constructor function `new` returns the Slave interface.
-    pub fn new(path: *const ::libc::c_char) -> Result<Self> {
-        match Self::open(path, libc::O_RDWR, None) {
+    /// The slave isn't made the controlling terminal by the open,
+    /// see the method `set_controlling_terminal`.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = CString::new(path.as_ref().as_os_str().as_bytes())
+            .map_err(|err| SlaveError::BadDescriptor(DescriptorError::OpenFail(err.into())))?;
+
+        match Self::open(&path, libc::O_RDWR | libc::O_NOCTTY, None) {
             Err(cause) => Err(SlaveError::BadDescriptor(cause)),
-            Ok(fd) => Ok(Slave { pty: fd }),
+            Ok(fd) => Ok(unsafe { Slave::from_raw_fd(fd) }),
+        }
+    }
+
+    /// The constructor function `from_master` opens the slave of the
+    /// master's pty. On Linux, it's obtained from the master with
+    /// `TIOCGPTPEER`, which doesn't depend on the devpts mounted at
+    /// the slave's path; the path is the fallback before Linux 4.13.
+    pub fn from_master(master: &Master) -> Result<Self> {
+        Slave::peer(master.as_raw_fd()).unwrap_or_else(|| {
+            master.slave_path()
+                .map_err(|err| SlaveError::BadDescriptor(DescriptorError::OpenFail(err)))
+                .and_then(Slave::new)
+        })
+    }
+
+    /// The constructor function `from_peer` opens the slave like
+    /// `from_master` with the path already known, without allocation,
+    /// in a freshly forked child.
+    pub(crate) fn from_peer(master: RawFd, path: &CStr) -> Result<Self> {
+        Slave::peer(master).unwrap_or_else(|| {
+            match Self::open(path, libc::O_RDWR | libc::O_NOCTTY, None) {
+                Err(cause) => Err(SlaveError::BadDescriptor(cause)),
+                Ok(fd) => Ok(unsafe { Slave::from_raw_fd(fd) }),
+            }
+        })
+    }
+
+    /// The function `peer` opens the slave with `TIOCGPTPEER`,
+    /// or returns none when the kernel doesn't know it.
+    #[cfg(target_os = "linux")]
+    fn peer(master: RawFd) -> Option<Result<Self>> {
+        // The request's type varies between the platforms.
+        #[allow(trivial_numeric_casts)]
+        let request = libc::TIOCGPTPEER as _;
+
+        match unsafe { libc::ioctl(master, request, libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+
+                match err.raw_os_error() {
+                    Some(libc::EINVAL) | Some(libc::ENOTTY) => None,
+                    _ => Some(Err(SlaveError::BadDescriptor(DescriptorError::OpenFail(err)))),
+                }
+            }
+            fd => Some(Ok(unsafe { Slave::from_raw_fd(fd) })),
         }
     }
 
+    /// The function `peer` returns none, `TIOCGPTPEER` is Linux only.
+    #[cfg(not(target_os = "linux"))]
+    fn peer(_: RawFd) -> Option<Result<Self>> {
+        None
+    }
+
+    /// The function `dup2` plugs the slave on the fd std, which stays
+    /// open on exec. When the slave already has this number, `dup2`(2)
+    /// does nothing, so its close-on-exec flag is cleared instead.
     pub fn dup2(&self, std: libc::c_int) -> Result<libc::c_int> {
         unsafe {
+            if self.as_raw_fd() == std {
+                return match libc::fcntl(std, libc::F_SETFD, 0) {
+                    -1 => Err(SlaveError::Dup2Error(io::Error::last_os_error())),
+                    _ => Ok(std),
+                };
+            }
             match libc::dup2(self.as_raw_fd(), std) {
-                -1 => Err(SlaveError::Dup2Error),
+                -1 => Err(SlaveError::Dup2Error(io::Error::last_os_error())),
                 d => Ok(d),
             }
         }
     }
+
+    /// Makes the pty the controlling terminal of the session of the
+    /// calling process, which must be its leader without one yet.
+    pub fn set_controlling_terminal(&self) -> Result<()> {
+        // The request's type varies between the platforms.
+        #[allow(trivial_numeric_casts)]
+        let request = libc::TIOCSCTTY as _;
+
+        unsafe {
+            match libc::ioctl(self.as_raw_fd(), request, 0) {
+                -1 => Err(SlaveError::ControllingTerminalError(io::Error::last_os_error())),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// Returns the window size of the pty.
+    pub fn window_size(&self) -> Result<WindowSize> {
+        WindowSize::from_fd(self.as_raw_fd()).map_err(SlaveError::WindowSizeError)
+    }
+
+    /// Resizes the pty, the foreground process group of the
+    /// slave receives a `SIGWINCH`.
+    pub fn set_window_size(&self, size: WindowSize) -> Result<()> {
+        size.apply(self.as_raw_fd()).map_err(SlaveError::WindowSizeError)
+    }
+
+    /// Returns the line discipline's configuration of the pty.
+    pub fn termios(&self) -> Result<Termios> {
+        Termios::from_fd(self.as_raw_fd()).map_err(SlaveError::TermiosError)
+    }
+
+    /// Configures the line discipline of the pty right away.
+    pub fn set_termios(&self, termios: &Termios) -> Result<()> {
+        termios.apply(self.as_raw_fd()).map_err(SlaveError::TermiosError)
+    }
 }
 
 impl Descriptor for Slave {}
@@ -35,12 +144,28 @@ impl Descriptor for Slave {}
 impl AsRawFd for Slave {
     /// The accessor function `as_raw_fd` returns the fd.
     fn as_raw_fd(&self) -> RawFd {
-        self.pty
+        self.pty.as_raw_fd()
+    }
+}
+
+impl AsFd for Slave {
+    /// The accessor function `as_fd` borrows the fd.
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.pty.as_fd()
+    }
+}
+
+impl IntoRawFd for Slave {
+    /// The function `into_raw_fd` gives up the ownership of the fd.
+    fn into_raw_fd(self) -> RawFd {
+        self.pty.into_raw_fd()
     }
 }
 
-impl Drop for Slave {
-    fn drop(&mut self) {
-        Descriptor::drop(self);
+impl FromRawFd for Slave {
+    /// The constructor function `from_raw_fd` takes the ownership
+    /// of fd, which must be an open slave's pty.
+    unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Slave { pty: OwnedFd::from_raw_fd(fd) }
     }
 }
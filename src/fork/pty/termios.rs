@@ -0,0 +1,116 @@
+use ::libc;
+
+use std::fmt;
+use std::io;
+use std::mem;
+use std::os::unix::io::RawFd;
+
+/// The struct `Termios` is the line discipline's configuration of
+/// a terminal, as read by `tcgetattr`(3) and written by `tcsetattr`(3).
+#[derive(Clone, Copy)]
+pub struct Termios {
+    inner: libc::termios,
+}
+
+impl Termios {
+    /// The constructor function `from_fd` reads the configuration
+    /// of the terminal to which fd refers.
+    pub fn from_fd(fd: RawFd) -> io::Result<Self> {
+        unsafe {
+            let mut inner: libc::termios = mem::zeroed();
+
+            match libc::tcgetattr(fd, &mut inner) {
+                -1 => Err(io::Error::last_os_error()),
+                _ => Ok(Termios { inner }),
+            }
+        }
+    }
+
+    /// The function `apply` configures the terminal to which fd
+    /// refers right away.
+    pub fn apply(&self, fd: RawFd) -> io::Result<()> {
+        unsafe {
+            match libc::tcsetattr(fd, libc::TCSANOW, &self.inner) {
+                -1 => Err(io::Error::last_os_error()),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// The preset `raw` disables any processing of the input and
+    /// of the output, like `cfmakeraw`(3).
+    pub fn raw(mut self) -> Self {
+        unsafe {
+            libc::cfmakeraw(&mut self.inner);
+        }
+        self
+    }
+
+    /// The preset `cbreak` delivers the input byte per byte without
+    /// echo, but keeps the signals and the output processing.
+    pub fn cbreak(mut self) -> Self {
+        self.inner.c_lflag &= !(libc::ICANON | libc::ECHO);
+        self.inner.c_cc[libc::VMIN] = 1;
+        self.inner.c_cc[libc::VTIME] = 0;
+        self
+    }
+
+    /// Turns the echo of the input on or off.
+    pub fn echo(mut self, on: bool) -> Self {
+        if on {
+            self.inner.c_lflag |= libc::ECHO;
+        } else {
+            self.inner.c_lflag &= !(libc::ECHO | libc::ECHOE | libc::ECHOK | libc::ECHONL);
+        }
+        self
+    }
+
+    /// Turns the canonical (line by line) input on or off.
+    pub fn canonical(mut self, on: bool) -> Self {
+        if on {
+            self.inner.c_lflag |= libc::ICANON;
+        } else {
+            self.inner.c_lflag &= !libc::ICANON;
+        }
+        self
+    }
+
+    /// Returns whether the input is echoed.
+    pub fn is_echo(&self) -> bool {
+        self.inner.c_lflag & libc::ECHO != 0
+    }
+
+    /// Returns whether the input is canonical.
+    pub fn is_canonical(&self) -> bool {
+        self.inner.c_lflag & libc::ICANON != 0
+    }
+
+    /// The accessor function `as_raw` returns the `termios` structure.
+    pub fn as_raw(&self) -> &libc::termios {
+        &self.inner
+    }
+
+    /// The accessor function `as_raw_mut` returns the `termios`
+    /// structure for the flags without preset.
+    pub fn as_raw_mut(&mut self) -> &mut libc::termios {
+        &mut self.inner
+    }
+}
+
+impl From<libc::termios> for Termios {
+    fn from(inner: libc::termios) -> Self {
+        Termios { inner }
+    }
+}
+
+impl fmt::Debug for Termios {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Termios")
+            .field("c_iflag", &self.inner.c_iflag)
+            .field("c_oflag", &self.inner.c_oflag)
+            .field("c_cflag", &self.inner.c_cflag)
+            .field("c_lflag", &self.inner.c_lflag)
+            .field("c_cc", &self.inner.c_cc)
+            .finish()
+    }
+}
@@ -0,0 +1,73 @@
+use ::libc;
+
+use std::io;
+use std::os::unix::io::RawFd;
+
+/// The struct `WindowSize` is the size of a terminal, as read
+/// by `TIOCGWINSZ` and written by `TIOCSWINSZ`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowSize {
+    /// Number of rows, in characters.
+    pub rows: u16,
+    /// Number of columns, in characters.
+    pub cols: u16,
+    /// Width, in pixels.
+    pub x_pixels: u16,
+    /// Height, in pixels.
+    pub y_pixels: u16,
+}
+
+impl WindowSize {
+    /// The constructor function `new` returns a size of `rows`
+    /// by `cols` characters without pixel dimensions.
+    pub fn new(rows: u16, cols: u16) -> Self {
+        WindowSize {
+            rows,
+            cols,
+            x_pixels: 0,
+            y_pixels: 0,
+        }
+    }
+
+    /// The constructor function `from_fd` reads the size of
+    /// the terminal to which fd refers.
+    pub fn from_fd(fd: RawFd) -> io::Result<Self> {
+        let mut winsize = libc::winsize {
+            ws_row: 0,
+            ws_col: 0,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+
+        unsafe {
+            match libc::ioctl(fd, libc::TIOCGWINSZ, &mut winsize) {
+                -1 => Err(io::Error::last_os_error()),
+                _ => {
+                    Ok(WindowSize {
+                        rows: winsize.ws_row,
+                        cols: winsize.ws_col,
+                        x_pixels: winsize.ws_xpixel,
+                        y_pixels: winsize.ws_ypixel,
+                    })
+                }
+            }
+        }
+    }
+
+    /// The function `apply` resizes the terminal to which fd refers.
+    pub fn apply(&self, fd: RawFd) -> io::Result<()> {
+        let winsize = libc::winsize {
+            ws_row: self.rows,
+            ws_col: self.cols,
+            ws_xpixel: self.x_pixels,
+            ws_ypixel: self.y_pixels,
+        };
+
+        unsafe {
+            match libc::ioctl(fd, libc::TIOCSWINSZ, &winsize) {
+                -1 => Err(io::Error::last_os_error()),
+                _ => Ok(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use ::libc;
+
+use std::io;
+use std::time::Duration;
+
+use super::process::Process;
+use super::signal::{self, Signal};
+use super::status::ExitStatus;
+
+/// The struct `ShutdownPolicy` defines how long a child is given
+/// to exit after each signal of a shutdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShutdownPolicy {
+    /// Grace period after the `SIGHUP`.
+    pub hangup: Duration,
+    /// Grace period after the `SIGTERM`, before the `SIGKILL`.
+    pub terminate: Duration,
+}
+
+impl ShutdownPolicy {
+    /// The constructor function `new` returns a policy with
+    /// the given grace periods.
+    pub fn new(hangup: Duration, terminate: Duration) -> Self {
+        ShutdownPolicy {
+            hangup,
+            terminate,
+        }
+    }
+}
+
+impl Default for ShutdownPolicy {
+    /// The default policy gives one second after each signal.
+    fn default() -> Self {
+        ShutdownPolicy::new(Duration::from_secs(1), Duration::from_secs(1))
+    }
+}
+
+/// The function `shutdown` sends `SIGHUP`, then `SIGTERM`, then
+/// `SIGKILL` to the process group of the child until it exits,
+/// and reaps it. A child already reaped isn't signaled again.
+pub(crate) fn shutdown(process: &Process, policy: ShutdownPolicy) -> io::Result<ExitStatus> {
+    let pid = process.pid();
+
+    for &(signal, grace) in &[(Signal::Hangup, policy.hangup),
+                              (Signal::Terminate, policy.terminate)] {
+        if let Some(status) = process.try_wait()? {
+            return Ok(status);
+        }
+        send(pid, signal);
+        // A stopped job would not handle the signal until resumed.
+        send(pid, Signal::Continue);
+        if let Some(status) = process.wait_timeout(grace)? {
+            return Ok(status);
+        }
+    }
+    send(pid, Signal::Kill);
+    process.wait()
+}
+
+/// The function `send` signals the process group led by the child,
+/// or the child alone when the group can't be signaled.
+fn send(pid: libc::pid_t, signal: Signal) {
+    if signal::killpg(pid, signal).is_err() {
+        let _ = signal::kill(pid, signal);
+    }
+}
@@ -0,0 +1,82 @@
+use ::libc;
+
+use std::io;
+
+/// The enum `Signal` defines the signals which can be sent to
+/// the child, its process group or the foreground job of the pty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// `SIGHUP`, the terminal hung up.
+    Hangup,
+    /// `SIGINT`, the interrupt character (`^C`) was typed.
+    Interrupt,
+    /// `SIGQUIT`, the quit character (`^\`) was typed.
+    Quit,
+    /// `SIGKILL`, can't be caught nor ignored.
+    Kill,
+    /// `SIGTERM`, the polite request to terminate.
+    Terminate,
+    /// `SIGCONT`, resumes a stopped process.
+    Continue,
+    /// `SIGSTOP`, stops the process, can't be caught nor ignored.
+    Stop,
+    /// `SIGTSTP`, the suspend character (`^Z`) was typed.
+    TerminalStop,
+    /// `SIGWINCH`, the window size changed.
+    WindowChange,
+    /// `SIGALRM`, a timer expired.
+    Alarm,
+    /// `SIGPIPE`, a write on a pipe without reader.
+    Pipe,
+    /// `SIGUSR1`, user-defined.
+    User1,
+    /// `SIGUSR2`, user-defined.
+    User2,
+}
+
+impl Signal {
+    /// The accessor function `as_raw` returns the signal number.
+    pub fn as_raw(&self) -> libc::c_int {
+        match *self {
+            Signal::Hangup => libc::SIGHUP,
+            Signal::Interrupt => libc::SIGINT,
+            Signal::Quit => libc::SIGQUIT,
+            Signal::Kill => libc::SIGKILL,
+            Signal::Terminate => libc::SIGTERM,
+            Signal::Continue => libc::SIGCONT,
+            Signal::Stop => libc::SIGSTOP,
+            Signal::TerminalStop => libc::SIGTSTP,
+            Signal::WindowChange => libc::SIGWINCH,
+            Signal::Alarm => libc::SIGALRM,
+            Signal::Pipe => libc::SIGPIPE,
+            Signal::User1 => libc::SIGUSR1,
+            Signal::User2 => libc::SIGUSR2,
+        }
+    }
+}
+
+impl From<Signal> for libc::c_int {
+    fn from(signal: Signal) -> Self {
+        signal.as_raw()
+    }
+}
+
+/// The function `kill` sends the signal to a process.
+pub(crate) fn kill(pid: libc::pid_t, signal: Signal) -> io::Result<()> {
+    unsafe {
+        match libc::kill(pid, signal.as_raw()) {
+            -1 => Err(io::Error::last_os_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The function `killpg` sends the signal to a process group.
+pub(crate) fn killpg(pgrp: libc::pid_t, signal: Signal) -> io::Result<()> {
+    unsafe {
+        match libc::killpg(pgrp, signal.as_raw()) {
+            -1 => Err(io::Error::last_os_error()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use ::descriptor;
+use ::libc;
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::os::unix::io::RawFd;
+use std::sync::Mutex;
+
+use super::signal::{self, Signal};
+use super::status;
+
+/// The enum `SpawnError` defines the step of the child's setup
+/// which failed, with the errno reported back to the parent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpawnError {
+    /// Can't start a new session.
+    Setsid { errno: libc::c_int },
+    /// Can't open the slave.
+    OpenSlave { errno: libc::c_int },
+    /// Can't make the slave the controlling terminal.
+    ControllingTerminal { errno: libc::c_int },
+    /// Can't plug the slave on the standard streams.
+    Dup2 { errno: libc::c_int },
+    /// Can't change the working directory.
+    Chdir { errno: libc::c_int },
+    /// Can't execute the program.
+    Exec { errno: libc::c_int },
+}
+
+impl SpawnError {
+    /// The accessor function `errno` returns the errno of the step.
+    pub fn errno(&self) -> libc::c_int {
+        match *self {
+            SpawnError::Setsid { errno } |
+            SpawnError::OpenSlave { errno } |
+            SpawnError::ControllingTerminal { errno } |
+            SpawnError::Dup2 { errno } |
+            SpawnError::Chdir { errno } |
+            SpawnError::Exec { errno } => errno,
+        }
+    }
+
+    /// The function `step` returns the name of the failed step.
+    fn step(&self) -> &'static str {
+        match *self {
+            SpawnError::Setsid { .. } => "setsid",
+            SpawnError::OpenSlave { .. } => "open the slave",
+            SpawnError::ControllingTerminal { .. } => "TIOCSCTTY",
+            SpawnError::Dup2 { .. } => "dup2",
+            SpawnError::Chdir { .. } => "chdir",
+            SpawnError::Exec { .. } => "exec",
+        }
+    }
+
+    /// The function `encode` packs the report into one write.
+    fn encode(&self) -> [libc::c_int; 2] {
+        let tag = match *self {
+            SpawnError::Setsid { .. } => 1,
+            SpawnError::OpenSlave { .. } => 2,
+            SpawnError::Dup2 { .. } => 3,
+            SpawnError::Chdir { .. } => 4,
+            SpawnError::Exec { .. } => 5,
+            SpawnError::ControllingTerminal { .. } => 6,
+        };
+
+        [tag, self.errno()]
+    }
+
+    /// The function `decode` unpacks a report written by `encode`.
+    fn decode(report: [libc::c_int; 2]) -> Option<Self> {
+        let errno = report[1];
+
+        match report[0] {
+            1 => Some(SpawnError::Setsid { errno }),
+            2 => Some(SpawnError::OpenSlave { errno }),
+            3 => Some(SpawnError::Dup2 { errno }),
+            4 => Some(SpawnError::Chdir { errno }),
+            5 => Some(SpawnError::Exec { errno }),
+            6 => Some(SpawnError::ControllingTerminal { errno }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SpawnError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.step(), io::Error::from_raw_os_error(self.errno()))
+    }
+}
+
+impl Error for SpawnError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        "the child can't be set up, errno is reported by the child."
+    }
+}
+
+impl From<SpawnError> for io::Error {
+    /// The function `from` keeps the kind of the errno.
+    fn from(err: SpawnError) -> Self {
+        io::Error::new(io::Error::from_raw_os_error(err.errno()).kind(), err)
+    }
+}
+
+/// The lock `FORKING` is held from the creation of a status pipe
+/// until the parent closed its write end, so that no other child
+/// forked meanwhile inherits it: the parent would wait for that
+/// child to exit as well.
+static FORKING: Mutex<()> = Mutex::new(());
+
+/// The enum `Forked` is the side of `fork` which returns.
+pub(crate) enum Forked<T> {
+    /// In the parent, the pid of a child whose setup succeeded.
+    Parent(libc::pid_t),
+    /// In the child, the value returned by its setup.
+    Child(T),
+}
+
+/// The function `fork` forks, runs `setup` in the child and, in the
+/// parent, waits until the child reports a failed setup or closes
+/// its end of the pipe. A child whose setup failed exits and is
+/// reaped. `setup` must only make async-signal-safe calls.
+pub(crate) unsafe fn fork<T, F>(setup: F) -> io::Result<Result<Forked<T>, SpawnError>>
+    where F: FnOnce() -> Result<T, SpawnError>
+{
+    let forking = FORKING.lock().unwrap_or_else(|e| e.into_inner());
+    // A successful `exec`(3) closes the child's end without a report.
+    let (read, write) = descriptor::cloexec_pipe(0)?;
+
+    match libc::fork() {
+        -1 => {
+            let err = io::Error::last_os_error();
+
+            libc::close(read);
+            libc::close(write);
+            Err(err)
+        }
+        0 => {
+            libc::close(read);
+            match setup() {
+                Err(cause) => report(write, cause),
+                Ok(value) => {
+                    libc::close(write);
+                    // The child's copy of the lock is released too,
+                    // for its own forks.
+                    drop(forking);
+                    Ok(Ok(Forked::Child(value)))
+                }
+            }
+        }
+        pid => {
+            libc::close(write);
+            drop(forking);
+            receive(pid, read).map(|report| match report {
+                None => Ok(Forked::Parent(pid)),
+                Some(cause) => Err(cause),
+            })
+        }
+    }
+}
+
+/// The function `report` writes the failed step to the parent and
+/// exits the child. It's async-signal-safe.
+fn report(fd: RawFd, err: SpawnError) -> ! {
+    let report = err.encode();
+
+    unsafe {
+        // Smaller than `PIPE_BUF`, so written at once.
+        while libc::write(fd,
+                          report.as_ptr() as *const libc::c_void,
+                          size_of_val(&report)) == -1 &&
+              io::Error::last_os_error().raw_os_error() == Some(libc::EINTR) {}
+        libc::_exit(127)
+    }
+}
+
+/// The function `receive` closes the parent's end once the child
+/// reported a failed setup, or closed its end. A child which failed
+/// is reaped, and so is a child which sent an unknown report.
+fn receive(pid: libc::pid_t, fd: RawFd) -> io::Result<Option<SpawnError>> {
+    let mut report: [libc::c_int; 2] = [0; 2];
+    let result = loop {
+        match unsafe {
+            libc::read(fd,
+                       report.as_mut_ptr() as *mut libc::c_void,
+                       size_of_val(&report))
+        } {
+            -1 => {
+                let err = io::Error::last_os_error();
+
+                if err.raw_os_error() != Some(libc::EINTR) {
+                    break Err(err);
+                }
+            }
+            0 => break Ok(None),
+            len if len as usize == size_of_val(&report) => {
+                break match SpawnError::decode(report) {
+                    None => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown spawn report")),
+                    cause => Ok(cause),
+                };
+            }
+            _ => break Err(io::Error::new(io::ErrorKind::InvalidData, "truncated spawn report")),
+        }
+    };
+
+    unsafe { libc::close(fd) };
+    match result {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            let _ = status::wait(pid);
+        }
+        Err(_) => {
+            let _ = signal::kill(pid, Signal::Kill);
+            let _ = status::wait(pid);
+        }
+    }
+    result
+}
@@ -0,0 +1,143 @@
+use ::libc;
+
+use std::fmt;
+use std::io;
+use std::os::unix::process::ExitStatusExt;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The struct `ExitStatus` is the status of a child, as reported
+/// by `waitpid`(2).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExitStatus(libc::c_int);
+
+impl ExitStatus {
+    /// The constructor function `from_raw` wraps a status
+    /// returned by `waitpid`(2).
+    pub fn from_raw(status: libc::c_int) -> Self {
+        ExitStatus(status)
+    }
+
+    /// The accessor function `into_raw` returns the `waitpid`(2) status.
+    pub fn into_raw(self) -> libc::c_int {
+        self.0
+    }
+
+    /// Returns whether the child exited with a zero code.
+    pub fn success(&self) -> bool {
+        self.code() == Some(0)
+    }
+
+    /// Returns the exit code, if the child exited normally.
+    pub fn code(&self) -> Option<i32> {
+        if libc::WIFEXITED(self.0) {
+            Some(libc::WEXITSTATUS(self.0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the signal which terminated the child, if any.
+    pub fn signal(&self) -> Option<i32> {
+        if libc::WIFSIGNALED(self.0) {
+            Some(libc::WTERMSIG(self.0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the child dumped a core when terminated.
+    pub fn core_dumped(&self) -> bool {
+        libc::WIFSIGNALED(self.0) && libc::WCOREDUMP(self.0)
+    }
+
+    /// Returns the signal which stopped the child, if any. The
+    /// statuses returned by `wait`, `try_wait` and `wait_timeout`
+    /// are terminations only, so this decodes a status built with
+    /// `from_raw`.
+    pub fn stopped_signal(&self) -> Option<i32> {
+        if libc::WIFSTOPPED(self.0) {
+            Some(libc::WSTOPSIG(self.0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the child was resumed by a `SIGCONT`. Like
+    /// `stopped_signal`, this only holds for a status built with
+    /// `from_raw`.
+    pub fn continued(&self) -> bool {
+        libc::WIFCONTINUED(self.0)
+    }
+}
+
+impl From<ExitStatus> for process::ExitStatus {
+    fn from(status: ExitStatus) -> Self {
+        process::ExitStatus::from_raw(status.0)
+    }
+}
+
+impl fmt::Display for ExitStatus {
+    /// The function `fmt` formats the value like `std::process::ExitStatus`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        process::ExitStatus::from(*self).fmt(f)
+    }
+}
+
+/// The function `wait` blocks until the child changes of state.
+pub(crate) fn wait(pid: libc::pid_t) -> io::Result<ExitStatus> {
+    loop {
+        if let Some(status) = waitpid(pid, 0)? {
+            return Ok(status);
+        }
+    }
+}
+
+/// The function `try_wait` returns the status of the child
+/// if it's terminated, without blocking.
+pub(crate) fn try_wait(pid: libc::pid_t) -> io::Result<Option<ExitStatus>> {
+    waitpid(pid, libc::WNOHANG)
+}
+
+/// The function `wait_timeout` waits for the child during
+/// at most `timeout`, polling with a growing delay.
+pub(crate) fn wait_timeout(pid: libc::pid_t, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_millis(1);
+
+    loop {
+        if let Some(status) = try_wait(pid)? {
+            return Ok(Some(status));
+        }
+
+        let now = Instant::now();
+
+        if now >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(delay.min(deadline - now));
+        delay = (delay * 2).min(Duration::from_millis(50));
+    }
+}
+
+/// The function `waitpid` calls `waitpid`(2) again when interrupted.
+fn waitpid(pid: libc::pid_t, options: libc::c_int) -> io::Result<Option<ExitStatus>> {
+    let mut status = 0;
+
+    loop {
+        unsafe {
+            match libc::waitpid(pid, &mut status, options) {
+                0 => return Ok(None),
+                -1 => {
+                    let err = io::Error::last_os_error();
+
+                    if err.kind() != io::ErrorKind::Interrupted {
+                        return Err(err);
+                    }
+                }
+                _ => return Ok(Some(ExitStatus(status))),
+            }
+        }
+    }
+}
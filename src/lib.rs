@@ -89,9 +89,42 @@
 
 extern crate libc;
 extern crate errno;
+#[cfg(feature = "async-io")]
+extern crate async_io;
+#[cfg(feature = "async-io")]
+extern crate futures_io;
+#[cfg(feature = "mio")]
+extern crate mio;
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "tokio")]
+extern crate tokio;
 
-mod descriptor;
+#[cfg(any(feature = "async-io", feature = "tokio"))]
+pub mod aio;
+pub mod descriptor;
+pub mod expect;
+pub mod command;
 pub mod fork;
+pub mod pair;
+pub mod poll;
 pub mod prelude;
+pub mod raw;
+pub mod resize;
+pub mod session;
+mod sigpipe;
 
-const DEFAULT_PTMX: &'static str = "/dev/ptmx";
+pub use command::Command;
+pub use pair::{open, open_with};
+pub use session::interact;
+
+const DEFAULT_PTMX: &str = "/dev/ptmx";
+
+/// The function `error_kind` returns the kind of the `io::Error`
+/// at the bottom of the chain of causes of `err`.
+fn error_kind(err: &(dyn std::error::Error + 'static)) -> std::io::ErrorKind {
+    match err.downcast_ref::<std::io::Error>() {
+        Some(err) => err.kind(),
+        None => err.source().map_or(std::io::ErrorKind::Other, error_kind),
+    }
+}
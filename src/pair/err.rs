@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use ::fork::{MasterError, SlaveError};
+
+/// The alias `Result` learns `PairError` possibility.
+pub type Result<T> = ::std::result::Result<T, PairError>;
+
+/// The enum `PairError` defines the possible errors
+/// from the function `open`.
+#[derive(Debug)]
+pub enum PairError {
+    /// The Master occured a error.
+    BadMaster(MasterError),
+    /// The Slave occured a error.
+    BadSlave(SlaveError),
+}
+
+impl fmt::Display for PairError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PairError::BadMaster(ref err) => write!(f, "master: {}", err),
+            PairError::BadSlave(ref err) => write!(f, "slave: {}", err),
+        }
+    }
+}
+
+impl Error for PairError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            PairError::BadMaster(_) => "the master as occured an error",
+            PairError::BadSlave(_) => "the slave as occured an error",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PairError::BadMaster(ref err) => Some(err),
+            PairError::BadSlave(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<PairError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: PairError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -0,0 +1,24 @@
+mod err;
+
+use ::fork::{self, Master, Slave, Termios, WindowSize};
+
+pub use self::err::{PairError, Result};
+
+/// The function `open` allocates a pty pair without forking, like
+/// `openpty`(3). The slave is opened with `O_NOCTTY`, so it doesn't
+/// become the controlling terminal of the calling process.
+pub fn open() -> Result<(Master, Slave)> {
+    open_with(None, None)
+}
+
+/// The function `open_with` allocates a pty pair like `open`, with
+/// the initial window size and a change of the line discipline's
+/// configuration, like `Termios::raw`, applied before it's returned.
+pub fn open_with(size: Option<WindowSize>,
+                 termios: Option<fn(Termios) -> Termios>)
+                 -> Result<(Master, Slave)> {
+    let master = fork::unlocked_master(::DEFAULT_PTMX, size, termios).map_err(PairError::BadMaster)?;
+    let slave = Slave::from_master(&master).map_err(PairError::BadSlave)?;
+
+    Ok((master, slave))
+}
@@ -0,0 +1,143 @@
+use ::libc;
+
+use std::io;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::time::{Duration, Instant};
+
+/// The enum `Interest` defines the readiness awaited on a fd.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interest {
+    /// Wait until the fd can be read without blocking.
+    Readable,
+    /// Wait until the fd can be written without blocking.
+    Writable,
+    /// Wait until the fd can be read or written without blocking.
+    Both,
+}
+
+impl Interest {
+    /// The accessor function `as_events` returns the `poll`(2) events.
+    fn as_events(&self) -> libc::c_short {
+        match *self {
+            Interest::Readable => libc::POLLIN,
+            Interest::Writable => libc::POLLOUT,
+            Interest::Both => libc::POLLIN | libc::POLLOUT,
+        }
+    }
+}
+
+/// The struct `Readiness` is what `poll`(2) reported on a fd.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Readiness(libc::c_short);
+
+impl Readiness {
+    /// Returns whether the fd can be read without blocking.
+    pub fn is_readable(&self) -> bool {
+        self.0 & libc::POLLIN != 0
+    }
+
+    /// Returns whether the fd can be written without blocking.
+    pub fn is_writable(&self) -> bool {
+        self.0 & libc::POLLOUT != 0
+    }
+
+    /// Returns whether the other side hung up, like a pty
+    /// whose slave is closed.
+    pub fn is_hangup(&self) -> bool {
+        self.0 & libc::POLLHUP != 0
+    }
+
+    /// Returns whether an error is pending on the fd.
+    pub fn is_error(&self) -> bool {
+        self.0 & (libc::POLLERR | libc::POLLNVAL) != 0
+    }
+
+    /// Returns whether anything was reported.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// The struct `PollFd` is a fd given to `poll` with its interest,
+/// and the readiness reported by the last call.
+#[derive(Clone, Copy, Debug)]
+pub struct PollFd {
+    fd: RawFd,
+    interest: Interest,
+    readiness: Readiness,
+}
+
+impl PollFd {
+    /// The constructor function `new` watches the fd of source.
+    pub fn new<T: AsRawFd>(source: &T, interest: Interest) -> Self {
+        PollFd::from_raw_fd(source.as_raw_fd(), interest)
+    }
+
+    /// The constructor function `from_raw_fd` watches the fd.
+    pub fn from_raw_fd(fd: RawFd, interest: Interest) -> Self {
+        PollFd {
+            fd,
+            interest,
+            readiness: Readiness::default(),
+        }
+    }
+
+    /// The accessor function `readiness` returns what the last
+    /// call of `poll` reported.
+    pub fn readiness(&self) -> Readiness {
+        self.readiness
+    }
+}
+
+impl AsRawFd for PollFd {
+    /// The accessor function `as_raw_fd` returns the fd.
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+/// The function `poll` waits until one of the fds is ready, or the
+/// timeout expires, and returns the number of fds ready. Without
+/// timeout, it waits forever. It's called again when interrupted.
+pub fn poll(fds: &mut [PollFd], timeout: Option<Duration>) -> io::Result<usize> {
+    let mut pollfds: Vec<libc::pollfd> = fds.iter()
+        .map(|fd| {
+            libc::pollfd {
+                fd: fd.fd,
+                events: fd.interest.as_events(),
+                revents: 0,
+            }
+        })
+        .collect();
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        let millis = match deadline {
+            None => -1,
+            Some(deadline) => {
+                let left = deadline.saturating_duration_since(Instant::now());
+                let millis = left.as_millis() + u128::from(left.subsec_nanos() % 1_000_000 != 0);
+
+                millis.min(libc::c_int::MAX as u128) as libc::c_int
+            }
+        };
+
+        unsafe {
+            match libc::poll(pollfds.as_mut_ptr(), pollfds.len() as libc::nfds_t, millis) {
+                -1 => {
+                    let err = io::Error::last_os_error();
+
+                    if err.kind() != io::ErrorKind::Interrupted {
+                        return Err(err);
+                    }
+                }
+                n => {
+                    for (fd, pollfd) in fds.iter_mut().zip(&pollfds) {
+                        fd.readiness = Readiness(pollfd.revents);
+                    }
+                    return Ok(n as usize);
+                }
+            }
+        }
+    }
+}
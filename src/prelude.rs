@@ -1,3 +1,14 @@
+pub use super::command::{Command, Child, CommandError};
+pub use super::command::{CommandExt, PtyChild};
+pub use super::descriptor::{Descriptor, DescriptorError};
+pub use super::expect::{Expect, ExpectError, Pattern};
 pub use super::fork::{Fork, ForkError};
-pub use super::fork::{Master, MasterError};
+pub use super::fork::{Master, MasterError, MasterReader, MasterWriter};
 pub use super::fork::{Slave, SlaveError};
+pub use super::fork::{Termios, WindowSize};
+pub use super::fork::{ExitNotifier, ExitStatus, Process, ShutdownPolicy, Signal, SpawnError};
+pub use super::pair::PairError;
+pub use super::poll::{Interest, PollFd, Readiness};
+pub use super::raw::{RawModeGuard, RawModeError};
+pub use super::resize::{ResizeForwarder, ResizeError};
+pub use super::session::{Outcome, Session, SessionError};
@@ -0,0 +1,51 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The alias `Result` learns `RawModeError` possibility.
+pub type Result<T> = ::std::result::Result<T, RawModeError>;
+
+/// The enum `RawModeError` defines the possible errors
+/// from constructor RawModeGuard.
+#[derive(Debug)]
+pub enum RawModeError {
+    /// Can't read the configuration of the terminal.
+    TcgetattrFail(io::Error),
+    /// Can't switch the terminal to raw mode.
+    TcsetattrFail(io::Error),
+}
+
+impl fmt::Display for RawModeError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RawModeError::TcgetattrFail(ref err) => write!(f, "tcgetattr: {}", err),
+            RawModeError::TcsetattrFail(ref err) => write!(f, "tcsetattr: {}", err),
+        }
+    }
+}
+
+impl Error for RawModeError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            RawModeError::TcgetattrFail(_) => "the `tcgetattr` has a error, errno is set appropriately.",
+            RawModeError::TcsetattrFail(_) => "the `tcsetattr` has a error, errno is set appropriately.",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RawModeError::TcgetattrFail(ref err) => Some(err),
+            RawModeError::TcsetattrFail(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<RawModeError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: RawModeError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
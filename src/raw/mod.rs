@@ -0,0 +1,55 @@
+mod err;
+
+use ::fork::Termios;
+use ::libc;
+
+pub use self::err::{RawModeError, Result};
+use std::os::unix::io::RawFd;
+
+/// The struct `RawModeGuard` switches a terminal, usually the one
+/// of the parent, to raw mode. The original configuration is
+/// restored when the guard is dropped, including during a panic.
+#[derive(Debug)]
+pub struct RawModeGuard {
+    fd: RawFd,
+    original: Termios,
+}
+
+impl RawModeGuard {
+    /// The constructor function `new` switches the terminal
+    /// to which fd refers to raw mode.
+    pub fn new(fd: RawFd) -> Result<Self> {
+        match Termios::from_fd(fd) {
+            Err(err) => Err(RawModeError::TcgetattrFail(err)),
+            Ok(original) => {
+                match original.raw().apply(fd) {
+                    Err(err) => Err(RawModeError::TcsetattrFail(err)),
+                    Ok(()) => {
+                        Ok(RawModeGuard {
+                            fd,
+                            original,
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    /// The constructor function `stdin` switches the terminal
+    /// on the standard input to raw mode.
+    pub fn stdin() -> Result<Self> {
+        RawModeGuard::new(libc::STDIN_FILENO)
+    }
+
+    /// The accessor function `original` returns the configuration
+    /// restored on drop.
+    pub fn original(&self) -> &Termios {
+        &self.original
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = self.original.apply(self.fd);
+    }
+}
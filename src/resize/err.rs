@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The alias `Result` learns `ResizeError` possibility.
+pub type Result<T> = ::std::result::Result<T, ResizeError>;
+
+/// The enum `ResizeError` defines the possible errors
+/// from constructor ResizeForwarder.
+#[derive(Debug)]
+pub enum ResizeError {
+    /// Can't create the self-pipe.
+    PipeFail(io::Error),
+    /// Can't install the `SIGWINCH` handler.
+    SigactionFail(io::Error),
+    /// Can't spawn the forwarding thread.
+    ThreadFail(io::Error),
+    /// Too many forwarders or signal handlers are already running.
+    TooMany,
+}
+
+impl fmt::Display for ResizeError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResizeError::PipeFail(ref err) => write!(f, "pipe: {}", err),
+            ResizeError::SigactionFail(ref err) => write!(f, "sigaction: {}", err),
+            ResizeError::ThreadFail(ref err) => write!(f, "thread: {}", err),
+            ResizeError::TooMany => write!(f, "too many forwarders or signal handlers are running"),
+        }
+    }
+}
+
+impl Error for ResizeError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            ResizeError::PipeFail(_) => "the `pipe` has a error, errno is set appropriately.",
+            ResizeError::SigactionFail(_) => "the `sigaction` has a error, errno is set appropriately.",
+            ResizeError::ThreadFail(_) => "can't spawn the forwarding thread",
+            ResizeError::TooMany => "too many forwarders or signal handlers are running",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ResizeError::PipeFail(ref err) => Some(err),
+            ResizeError::SigactionFail(ref err) => Some(err),
+            ResizeError::ThreadFail(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ResizeError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: ResizeError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
@@ -0,0 +1,98 @@
+mod err;
+
+use ::fork::{Master, WindowSize};
+use ::libc;
+use ::poll::{self, Interest, PollFd};
+use ::sigpipe::{Failure, SignalPipe};
+
+pub use self::err::{ResizeError, Result};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+/// The struct `ResizeForwarder` mirrors the size of a terminal into
+/// a pty each time the process receives a `SIGWINCH`. The handler
+/// only writes on a self-pipe; a thread applies the size to the
+/// master. Dropping the forwarder uninstalls it.
+#[derive(Debug)]
+pub struct ResizeForwarder {
+    pipe: Arc<SignalPipe>,
+    stop: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl ResizeForwarder {
+    /// The constructor function `new` mirrors the size of the
+    /// terminal on the standard input into the master.
+    pub fn new(master: Master) -> Result<Self> {
+        ResizeForwarder::from_fd(libc::STDIN_FILENO, master)
+    }
+
+    /// The constructor function `from_fd` mirrors the size of the
+    /// terminal to which tty refers into the master. The size is
+    /// applied once right away.
+    pub fn from_fd(tty: RawFd, master: Master) -> Result<Self> {
+        let pipe = Arc::new(SignalPipe::new(libc::SIGWINCH)?);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let pipe = pipe.clone();
+            let stop = stop.clone();
+
+            thread::Builder::new()
+                .name("pty-resize".to_string())
+                .spawn(move || forward(tty, master.as_raw_fd(), &pipe, &stop))
+        };
+
+        match thread {
+            Err(err) => Err(ResizeError::ThreadFail(err)),
+            Ok(thread) => {
+                Ok(ResizeForwarder {
+                    pipe,
+                    stop,
+                    thread: Some(thread),
+                })
+            }
+        }
+    }
+}
+
+impl Drop for ResizeForwarder {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            self.stop.store(true, Ordering::SeqCst);
+            self.pipe.wake();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl From<Failure> for ResizeError {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Pipe(err) => ResizeError::PipeFail(err),
+            Failure::Sigaction(err) => ResizeError::SigactionFail(err),
+            Failure::TooMany => ResizeError::TooMany,
+        }
+    }
+}
+
+/// The function `forward` is the body of the forwarding thread. It
+/// stops when the self-pipe can't be waited for anymore.
+fn forward(tty: RawFd, master: RawFd, pipe: &SignalPipe, stop: &AtomicBool) {
+    loop {
+        if let Ok(size) = WindowSize::from_fd(tty) {
+            let _ = size.apply(master);
+        }
+
+        let mut fds = [PollFd::new(pipe, Interest::Readable)];
+
+        if poll::poll(&mut fds, None).is_err() || fds[0].readiness().is_error() {
+            return;
+        }
+        pipe.drain();
+        if stop.load(Ordering::SeqCst) {
+            return;
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use ::raw::RawModeError;
+use ::resize::ResizeError;
+
+/// The alias `Result` learns `SessionError` possibility.
+pub type Result<T> = ::std::result::Result<T, SessionError>;
+
+/// The enum `SessionError` defines the possible errors
+/// from `Session::run`.
+#[derive(Debug)]
+pub enum SessionError {
+    /// Can't switch the input to raw mode.
+    RawMode(RawModeError),
+    /// Can't duplicate the master for the resize forwarder.
+    DupFail(io::Error),
+    /// Can't forward the size of the input.
+    Resize(ResizeError),
+    /// Can't wait for the input or the master.
+    PollFail(io::Error),
+    /// Can't read the input or the master.
+    ReadFail(io::Error),
+    /// Can't write to the output or the master.
+    WriteFail(io::Error),
+    /// Can't reap the child.
+    WaitpidFail(io::Error),
+}
+
+impl fmt::Display for SessionError {
+    /// The function `fmt` formats the value using the given formatter.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SessionError::RawMode(ref err) => write!(f, "raw mode: {}", err),
+            SessionError::DupFail(ref err) => write!(f, "dup: {}", err),
+            SessionError::Resize(ref err) => write!(f, "resize: {}", err),
+            SessionError::PollFail(ref err) => write!(f, "poll: {}", err),
+            SessionError::ReadFail(ref err) => write!(f, "read: {}", err),
+            SessionError::WriteFail(ref err) => write!(f, "write: {}", err),
+            SessionError::WaitpidFail(ref err) => write!(f, "waitpid: {}", err),
+        }
+    }
+}
+
+impl Error for SessionError {
+    /// The function `description` returns a short description of the error.
+    fn description(&self) -> &str {
+        match *self {
+            SessionError::RawMode(_) => "the input can't be switched to raw mode",
+            SessionError::DupFail(_) => "the `dup` has a error, errno is set appropriately.",
+            SessionError::Resize(_) => "the size of the input can't be forwarded",
+            SessionError::PollFail(_) => "the `poll` has a error, errno is set appropriately.",
+            SessionError::ReadFail(_) => "the `read` has a error, errno is set appropriately.",
+            SessionError::WriteFail(_) => "the `write` has a error, errno is set appropriately.",
+            SessionError::WaitpidFail(_) => "the `waitpid` has a error, errno is set appropriately.",
+        }
+    }
+
+    /// The function `source` returns the lower-level cause of this error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SessionError::RawMode(ref err) => Some(err),
+            SessionError::Resize(ref err) => Some(err),
+            SessionError::DupFail(ref err) |
+            SessionError::PollFail(ref err) |
+            SessionError::ReadFail(ref err) |
+            SessionError::WriteFail(ref err) |
+            SessionError::WaitpidFail(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<SessionError> for io::Error {
+    /// The function `from` keeps the kind of the lower-level cause.
+    fn from(err: SessionError) -> Self {
+        io::Error::new(::error_kind(&err), err)
+    }
+}
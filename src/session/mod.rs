@@ -0,0 +1,190 @@
+mod err;
+
+use ::command::Child;
+use ::fork::{ExitStatus, Master};
+use ::libc;
+use ::poll::{self, Interest, PollFd};
+use ::raw::RawModeGuard;
+use ::resize::ResizeForwarder;
+
+pub use self::err::{SessionError, Result};
+use std::io::{self, Read};
+use std::os::unix::io::{AsRawFd, RawFd};
+
+/// The escape key suggested for `Session::escape`, `^]` like telnet.
+pub const DEFAULT_ESCAPE: u8 = 0x1d;
+
+/// The size of the buffer of each copy.
+const BUFFER: usize = 4096;
+
+/// The enum `Outcome` defines how a session ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The child hung up the pty, and was reaped with this status.
+    Exited(ExitStatus),
+    /// The escape key was typed, the child is still running.
+    Detached,
+}
+
+/// The struct `Session` passes its input through to a child and the
+/// child's output back, like `script`(1). When the input is a
+/// terminal, it's switched to raw mode and its size is forwarded to
+/// the pty for the duration of the session.
+#[derive(Debug)]
+pub struct Session<'a> {
+    child: &'a Child,
+    input: RawFd,
+    output: RawFd,
+    escape: Option<u8>,
+}
+
+impl<'a> Session<'a> {
+    /// The constructor function `new` returns a session between the
+    /// standard streams and the child, without escape key.
+    pub fn new(child: &'a Child) -> Self {
+        Session {
+            child,
+            input: libc::STDIN_FILENO,
+            output: libc::STDOUT_FILENO,
+            escape: None,
+        }
+    }
+
+    /// Reads the keystrokes from fd instead of the standard input.
+    pub fn input(&mut self, fd: RawFd) -> &mut Self {
+        self.input = fd;
+        self
+    }
+
+    /// Writes the child's output to fd instead of the standard output.
+    pub fn output(&mut self, fd: RawFd) -> &mut Self {
+        self.output = fd;
+        self
+    }
+
+    /// Detaches from the child when the key is read from the input.
+    /// The key itself isn't forwarded.
+    pub fn escape(&mut self, key: Option<u8>) -> &mut Self {
+        self.escape = key;
+        self
+    }
+
+    /// Copies between the input, the output and the master until the
+    /// child hangs up the pty, then reaps it. The end of the input is
+    /// forwarded as the end-of-file character of the pty.
+    pub fn run(&self) -> Result<Outcome> {
+        let master = self.child.master();
+        let tty = unsafe { libc::isatty(self.input) } == 1;
+        let _raw = if tty {
+            Some(RawModeGuard::new(self.input).map_err(SessionError::RawMode)?)
+        } else {
+            None
+        };
+        let _resize = if tty {
+            let forwarded = master.try_clone().map_err(SessionError::DupFail)?;
+
+            Some(ResizeForwarder::from_fd(self.input, forwarded).map_err(SessionError::Resize)?)
+        } else {
+            None
+        };
+        let mut buf = [0; BUFFER];
+        let mut input_open = true;
+
+        loop {
+            let mut fds = [PollFd::new(master, Interest::Readable),
+                           PollFd::from_raw_fd(self.input, Interest::Readable)];
+            let watched = if input_open { 2 } else { 1 };
+
+            poll::poll(&mut fds[..watched], None).map_err(SessionError::PollFail)?;
+
+            if !fds[0].readiness().is_empty() {
+                match { master }.read(&mut buf) {
+                    Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                    Err(err) => return Err(SessionError::ReadFail(err)),
+                    Ok(0) => break,
+                    Ok(len) => write_all(self.output, &buf[..len]).map_err(SessionError::WriteFail)?,
+                }
+            }
+            if input_open && !fds[1].readiness().is_empty() {
+                match read(self.input, &mut buf) {
+                    Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                    Err(err) => return Err(SessionError::ReadFail(err)),
+                    Ok(0) => {
+                        input_open = false;
+                        write_master(master, &[end_of_file(master)])?;
+                    }
+                    Ok(len) => {
+                        let escaped = self.escape.and_then(|key| buf[..len].iter().position(|&c| c == key));
+
+                        write_master(master, &buf[..escaped.unwrap_or(len)])?;
+                        if escaped.is_some() {
+                            return Ok(Outcome::Detached);
+                        }
+                    }
+                }
+            }
+        }
+        self.child.process().wait().map(Outcome::Exited).map_err(SessionError::WaitpidFail)
+    }
+}
+
+/// The function `interact` runs a session between the standard
+/// streams and the child, see `Session::run`.
+pub fn interact(child: &Child) -> Result<Outcome> {
+    Session::new(child).run()
+}
+
+/// The function `end_of_file` returns the `VEOF` character of the
+/// pty, `^D` by default.
+fn end_of_file(master: &Master) -> u8 {
+    master.termios().map(|termios| termios.as_raw().c_cc[libc::VEOF]).unwrap_or(0x04)
+}
+
+/// The function `write_master` writes the keystrokes to the master.
+/// The hangup (`EIO` on Linux) isn't an error, the output still has
+/// to be drained until the master reports it.
+fn write_master(master: &Master, buf: &[u8]) -> Result<()> {
+    match write_all(master.as_raw_fd(), buf) {
+        Err(ref err) if err.raw_os_error() == Some(libc::EIO) => Ok(()),
+        Err(err) => Err(SessionError::WriteFail(err)),
+        Ok(()) => Ok(()),
+    }
+}
+
+/// The function `read` reads from fd, retrying on `EINTR`.
+fn read(fd: RawFd, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        match unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            len => return Ok(len as usize),
+        }
+    }
+}
+
+/// The function `write_all` writes the whole buffer to fd, retrying
+/// on `EINTR` and waiting when fd is in the nonblocking mode.
+fn write_all(fd: RawFd, mut buf: &[u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match unsafe { libc::write(fd, buf.as_ptr() as *const libc::c_void, buf.len()) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+
+                match err.kind() {
+                    io::ErrorKind::Interrupted => {}
+                    io::ErrorKind::WouldBlock => {
+                        poll::poll(&mut [PollFd::from_raw_fd(fd, Interest::Writable)], None)?;
+                    }
+                    _ => return Err(err),
+                }
+            }
+            len => buf = &buf[len as usize..],
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use ::descriptor;
+use ::libc;
+
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::ptr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicPtr, AtomicUsize, Ordering};
+
+/// The maximum number of distinct signals handled.
+const SIGNALS: usize = 4;
+
+/// The struct `Slot` is a self-pipe as seen by the handler. Slots
+/// and their pipes are never freed, only reused, so that the handler
+/// can walk the list without a lock and never writes on a fd closed
+/// meanwhile.
+#[derive(Debug)]
+struct Slot {
+    signal: AtomicI32,
+    busy: AtomicBool,
+    read: RawFd,
+    write: RawFd,
+    next: AtomicPtr<Slot>,
+}
+
+/// The struct `Chain` is the disposition which was installed
+/// before the handler, called after it.
+struct Chain {
+    signal: AtomicI32,
+    action: AtomicUsize,
+    flags: AtomicI32,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const FREE_CHAIN: Chain = Chain {
+    signal: AtomicI32::new(0),
+    action: AtomicUsize::new(0),
+    flags: AtomicI32::new(0),
+};
+
+/// The head of the list of slots, which grows with the number of
+/// self-pipes open at the same time.
+static SLOTS: AtomicPtr<Slot> = AtomicPtr::new(ptr::null_mut());
+
+static CHAINS: [Chain; SIGNALS] = [FREE_CHAIN; SIGNALS];
+
+/// The signals with a handler installed, how many self-pipes
+/// use each one, and the dispositions to restore.
+static INSTALLED: Mutex<Vec<Installed>> = Mutex::new(Vec::new());
+
+struct Installed {
+    signal: libc::c_int,
+    count: usize,
+    previous: libc::sigaction,
+}
+
+unsafe impl Send for Installed {}
+
+/// The enum `Failure` defines the possible errors from
+/// constructor SignalPipe.
+#[derive(Debug)]
+pub enum Failure {
+    /// Can't create the pipe.
+    Pipe(io::Error),
+    /// Can't install the handler.
+    Sigaction(io::Error),
+    /// Too many distinct signals.
+    TooMany,
+}
+
+impl From<Failure> for io::Error {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Pipe(err) | Failure::Sigaction(err) => err,
+            Failure::TooMany => io::Error::other("too many signals are handled"),
+        }
+    }
+}
+
+/// The struct `SignalPipe` is a nonblocking self-pipe which becomes
+/// readable each time the process receives the signal. The handler
+/// is installed with the first pipe of a signal, and calls the
+/// previous disposition after writing on the pipes. Dropping it
+/// keeps the pipe open for the next one.
+#[derive(Debug)]
+pub struct SignalPipe {
+    signal: libc::c_int,
+    slot: &'static Slot,
+}
+
+impl SignalPipe {
+    /// The constructor function `new` opens a self-pipe for signal.
+    pub fn new(signal: libc::c_int) -> Result<Self, Failure> {
+        let slot = acquire(signal)?;
+
+        if let Err(failure) = install(signal) {
+            release(slot);
+            return Err(failure);
+        }
+        Ok(SignalPipe { signal, slot })
+    }
+
+    /// The function `drain` reads all the pending notifications.
+    pub fn drain(&self) {
+        drain(self.slot.read);
+    }
+
+    /// The function `wake` makes the pipe readable as if the
+    /// signal was received.
+    pub fn wake(&self) {
+        notify(self.slot.write);
+    }
+}
+
+impl AsRawFd for SignalPipe {
+    /// The accessor function `as_raw_fd` returns the read end.
+    fn as_raw_fd(&self) -> RawFd {
+        self.slot.read
+    }
+}
+
+impl Drop for SignalPipe {
+    fn drop(&mut self) {
+        release(self.slot);
+        uninstall(self.signal);
+    }
+}
+
+/// The function `handle` is the signal handler.
+extern "C" fn handle(signal: libc::c_int, info: *mut libc::siginfo_t, context: *mut libc::c_void) {
+    let errno = ::errno::errno();
+
+    let mut slot = SLOTS.load(Ordering::SeqCst);
+
+    while let Some(current) = unsafe { slot.as_ref() } {
+        if current.signal.load(Ordering::SeqCst) == signal {
+            notify(current.write);
+        }
+        slot = current.next.load(Ordering::SeqCst);
+    }
+    for chain in CHAINS.iter() {
+        if chain.signal.load(Ordering::SeqCst) == signal {
+            let action = chain.action.load(Ordering::SeqCst);
+
+            if action != libc::SIG_DFL && action != libc::SIG_IGN {
+                unsafe {
+                    if chain.flags.load(Ordering::SeqCst) & libc::SA_SIGINFO != 0 {
+                        let action: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) =
+                            mem::transmute(action);
+
+                        action(signal, info, context);
+                    } else {
+                        let action: extern "C" fn(libc::c_int) = mem::transmute(action);
+
+                        action(signal);
+                    }
+                }
+            }
+        }
+    }
+    ::errno::set_errno(errno);
+}
+
+/// The function `install` installs the handler for the first
+/// self-pipe of the signal.
+fn install(signal: libc::c_int) -> Result<(), Failure> {
+    let mut installed = INSTALLED.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(entry) = installed.iter_mut().find(|entry| entry.signal == signal) {
+        entry.count += 1;
+        return Ok(());
+    }
+
+    let chain = match CHAINS.iter().find(|chain| chain.signal.load(Ordering::SeqCst) == 0) {
+        None => return Err(Failure::TooMany),
+        Some(chain) => chain,
+    };
+
+    let previous = unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        let mut previous: libc::sigaction = mem::zeroed();
+        let handler: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) = handle;
+
+        action.sa_sigaction = handler as libc::sighandler_t;
+        action.sa_flags = libc::SA_RESTART | libc::SA_SIGINFO;
+        libc::sigemptyset(&mut action.sa_mask);
+
+        // The previous disposition is read first so that it's
+        // chained as soon as the handler runs.
+        if libc::sigaction(signal, ptr::null(), &mut previous) == -1 {
+            return Err(Failure::Sigaction(io::Error::last_os_error()));
+        }
+        chain.action.store(previous.sa_sigaction, Ordering::SeqCst);
+        chain.flags.store(previous.sa_flags, Ordering::SeqCst);
+        chain.signal.store(signal, Ordering::SeqCst);
+        if libc::sigaction(signal, &action, &mut previous) == -1 {
+            chain.signal.store(0, Ordering::SeqCst);
+            return Err(Failure::Sigaction(io::Error::last_os_error()));
+        }
+        chain.action.store(previous.sa_sigaction, Ordering::SeqCst);
+        chain.flags.store(previous.sa_flags, Ordering::SeqCst);
+        previous
+    };
+
+    installed.push(Installed {
+        signal,
+        count: 1,
+        previous,
+    });
+    Ok(())
+}
+
+/// The function `uninstall` restores the previous disposition
+/// for the last self-pipe of the signal, unless another handler
+/// was installed meanwhile.
+fn uninstall(signal: libc::c_int) {
+    let mut installed = INSTALLED.lock().unwrap_or_else(|e| e.into_inner());
+    let index = match installed.iter().position(|entry| entry.signal == signal) {
+        None => return,
+        Some(index) => index,
+    };
+
+    installed[index].count -= 1;
+    if installed[index].count > 0 {
+        return;
+    }
+
+    unsafe {
+        let mut current: libc::sigaction = mem::zeroed();
+        let handler: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) = handle;
+
+        if libc::sigaction(signal, ptr::null(), &mut current) == -1 ||
+           current.sa_sigaction != handler as libc::sighandler_t {
+            // Someone chains to the handler: keep it, it only
+            // calls the previous disposition now.
+            return;
+        }
+        libc::sigaction(signal, &installed[index].previous, ptr::null_mut());
+    }
+    for chain in CHAINS.iter() {
+        if chain.signal.load(Ordering::SeqCst) == signal {
+            chain.signal.store(0, Ordering::SeqCst);
+        }
+    }
+    installed.remove(index);
+}
+
+/// The function `acquire` takes a free slot for signal, emptying
+/// its pipe, or adds one to the list with a new pipe.
+fn acquire(signal: libc::c_int) -> Result<&'static Slot, Failure> {
+    let mut slot = SLOTS.load(Ordering::SeqCst);
+
+    while let Some(current) = unsafe { slot.as_ref() } {
+        if current.busy.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            drain(current.read);
+            current.signal.store(signal, Ordering::SeqCst);
+            return Ok(current);
+        }
+        slot = current.next.load(Ordering::SeqCst);
+    }
+
+    let (read, write) = descriptor::cloexec_pipe(libc::O_NONBLOCK).map_err(Failure::Pipe)?;
+    // The slot is leaked: the handler may read it at any time.
+    let new = Box::into_raw(Box::new(Slot {
+        signal: AtomicI32::new(signal),
+        busy: AtomicBool::new(true),
+        read,
+        write,
+        next: AtomicPtr::new(SLOTS.load(Ordering::SeqCst)),
+    }));
+    let slot: &'static Slot = unsafe { &*new };
+
+    while let Err(head) = SLOTS.compare_exchange(slot.next.load(Ordering::SeqCst),
+                                                 new,
+                                                 Ordering::SeqCst,
+                                                 Ordering::SeqCst) {
+        slot.next.store(head, Ordering::SeqCst);
+    }
+    Ok(slot)
+}
+
+/// The function `release` frees the slot of a self-pipe. A handler
+/// running meanwhile may still write on its pipe, which is emptied
+/// when the slot is taken again.
+fn release(slot: &Slot) {
+    slot.signal.store(0, Ordering::SeqCst);
+    slot.busy.store(false, Ordering::SeqCst);
+}
+
+/// The function `drain` reads all the bytes pending on the pipe.
+fn drain(fd: RawFd) {
+    let mut buf = [0u8; 64];
+
+    unsafe {
+        while libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) > 0 {}
+    }
+}
+
+/// The function `notify` writes a byte on the pipe.
+fn notify(fd: RawFd) {
+    unsafe {
+        libc::write(fd, [0u8].as_ptr() as *const libc::c_void, 1);
+    }
+}
@@ -0,0 +1,14 @@
+use super::pty::prelude::*;
+
+use std::io::prelude::*;
+
+/// Reads the master byte by byte until the end of the line.
+pub fn read_line(mut master: &Master) -> String {
+    let mut buf = [0];
+    let mut res = String::new();
+    while buf[0] as char != '\n' {
+        master.read_exact(&mut buf).expect("cannot read 1 byte");
+        res.push(buf[0] as char)
+    }
+    res
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "async-io")]
+
+extern crate pty;
+extern crate libc;
+extern crate futures_lite;
+
+use self::pty::aio::async_io::{self, Exit};
+
+use self::futures_lite::future::{block_on, zip};
+use self::futures_lite::io::{AsyncReadExt, AsyncWriteExt};
+
+#[test]
+fn it_async_io() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line; exit 7"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let exit = Exit::new(child.process()).unwrap();
+    let mut master = async_io::master(child.master().try_clone().unwrap()).unwrap();
+    let mut output = Vec::new();
+
+    block_on(master.write_all(b"hi\n")).unwrap();
+    block_on(master.read_to_end(&mut output)).unwrap();
+
+    assert_eq!(String::from_utf8_lossy(&output).trim(), "got hi");
+    let status = block_on(exit).unwrap();
+
+    assert_eq!(status.code(), Some(7));
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+    assert_eq!(child.wait().unwrap(), status);
+}
+
+#[test]
+fn it_async_io_split() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let master = async_io::master(child.master().try_clone().unwrap()).unwrap();
+    let (mut reader, mut writer) = async_io::split(master);
+    let mut output = Vec::new();
+
+    let (written, read) = block_on(zip(writer.write_all(b"hi\n"), reader.read_to_end(&mut output)));
+
+    written.unwrap();
+    read.unwrap();
+    assert_eq!(String::from_utf8_lossy(&output).trim(), "got hi");
+    assert!(child.wait().unwrap().success());
+}
@@ -0,0 +1,103 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::env;
+use std::io::prelude::*;
+use std::os::unix::io::RawFd;
+use std::process;
+
+/// The function `isolated` runs the test named `name` again alone in
+/// a new process, where `scenario` can close the standard streams.
+fn isolated(name: &str, scenario: fn()) {
+    if env::var_os("PTY_TEST_ISOLATED").is_some() {
+        scenario();
+    } else {
+        let status = process::Command::new(env::current_exe().unwrap())
+            .args([name, "--test-threads=1", "--nocapture"])
+            .env("PTY_TEST_ISOLATED", "1")
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+    }
+}
+
+/// The function `without` closes the standard streams `fds` while
+/// `spawn` opens the master, which takes the lowest of them, and
+/// restores the others.
+fn without<T, F: FnOnce() -> T>(fds: &[RawFd], spawn: F) -> T {
+    let saved: Vec<RawFd> = fds.iter()
+        .map(|&fd| unsafe { libc::fcntl(fd, libc::F_DUPFD_CLOEXEC, 3) })
+        .collect();
+
+    for &fd in fds {
+        unsafe { libc::close(fd) };
+    }
+
+    let spawned = spawn();
+
+    for (&fd, &copy) in fds.iter().zip(&saved) {
+        unsafe {
+            if libc::fcntl(fd, libc::F_GETFD) == -1 {
+                libc::dup2(copy, fd);
+            }
+            libc::close(copy);
+        }
+    }
+    spawned
+}
+
+/// The function `output` reads the whole output of the child.
+fn output(master: &Master) -> String {
+    let mut string = String::new();
+
+    { master }.read_to_string(&mut string).unwrap();
+    string
+}
+
+#[test]
+fn it_spawn_pty_with_master_on_stdout() {
+    isolated("it_spawn_pty_with_master_on_stdout", || {
+        let mut command = process::Command::new("sh");
+
+        command.args(["-c", "echo out; echo err >&2"]);
+
+        let mut child = without(&[libc::STDOUT_FILENO], || command.spawn_pty(None, None).unwrap());
+        let printed = output(child.master());
+
+        assert!(printed.contains("out"), "{:?}", printed);
+        assert!(printed.contains("err"), "{:?}", printed);
+        assert!(child.child().wait().unwrap().success());
+    });
+}
+
+#[test]
+fn it_spawn_with_master_on_stdout() {
+    isolated("it_spawn_with_master_on_stdout", || {
+        let child = without(&[libc::STDOUT_FILENO], || {
+            pty::Command::new("sh").args(["-c", "echo out; echo err >&2"]).spawn().unwrap()
+        });
+        let printed = output(child.master());
+
+        assert!(printed.contains("out"), "{:?}", printed);
+        assert!(printed.contains("err"), "{:?}", printed);
+        assert!(child.wait().unwrap().success());
+    });
+}
+
+#[test]
+fn it_spawn_with_slave_on_stdout() {
+    isolated("it_spawn_with_slave_on_stdout", || {
+        // The master takes the stdin, the slave the stdout.
+        let child = without(&[libc::STDIN_FILENO, libc::STDOUT_FILENO], || {
+            pty::Command::new("sh").args(["-c", "echo out; echo err >&2"]).spawn().unwrap()
+        });
+        let printed = output(child.master());
+
+        assert!(printed.contains("out"), "{:?}", printed);
+        assert!(printed.contains("err"), "{:?}", printed);
+        assert!(child.wait().unwrap().success());
+    });
+}
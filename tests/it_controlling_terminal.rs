@@ -0,0 +1,78 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::os::unix::io::AsRawFd;
+use std::process;
+
+#[test]
+fn it_controlling_terminal() {
+    let child = pty::Command::new("sleep").arg("1").spawn().unwrap();
+    let sid = unsafe { libc::tcgetsid(child.master().as_raw_fd()) };
+
+    assert_eq!(sid, child.pid());
+    child.kill(Signal::Kill).unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn it_controlling_terminal_disabled() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "(: </dev/tty) 2>/dev/null && echo some || echo none"])
+        .controlling_terminal(false)
+        .spawn()
+        .unwrap();
+    let mut string = String::new();
+
+    child.master().read_to_string(&mut string).unwrap();
+
+    assert_eq!(string.trim(), "none");
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_fork_controlling_terminal() {
+    let fork = Fork::from_ptmx().unwrap();
+
+    if fork.is_parent().is_ok() {
+        assert!(fork.wait().unwrap().success());
+    } else {
+        unsafe {
+            let controlling = libc::tcgetsid(libc::STDIN_FILENO) == libc::getpid();
+
+            libc::_exit(if controlling { 0 } else { 1 });
+        }
+    }
+}
+
+#[test]
+fn it_fork_controlling_terminal_disabled() {
+    let fork = Fork::new_with("/dev/ptmx", None, false).unwrap();
+
+    if fork.is_parent().is_ok() {
+        assert!(fork.wait().unwrap().success());
+    } else {
+        unsafe {
+            let controlling = libc::tcgetsid(libc::STDIN_FILENO) == libc::getpid();
+
+            libc::_exit(if controlling { 1 } else { 0 });
+        }
+    }
+}
+
+#[test]
+fn it_spawn_pty_controlling_terminal_disabled() {
+    let mut command = process::Command::new("sh");
+
+    command.args(["-c", "(: </dev/tty) 2>/dev/null && echo some || echo none"]);
+
+    let mut child = command.spawn_pty_with(None, None, false).unwrap();
+    let mut string = String::new();
+
+    child.master().read_to_string(&mut string).unwrap();
+
+    assert_eq!(string.trim(), "none");
+    assert!(child.child().wait().unwrap().success());
+}
@@ -0,0 +1,29 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::os::unix::io::AsRawFd;
+
+fn is_cloexec<T: AsRawFd>(descriptor: &T) -> bool {
+    unsafe { libc::fcntl(descriptor.as_raw_fd(), libc::F_GETFD) & libc::FD_CLOEXEC != 0 }
+}
+
+#[test]
+fn it_opens_close_on_exec() {
+    let (master, slave) = pty::open().unwrap();
+    let path = master.slave_path().unwrap();
+
+    assert!(is_cloexec(&master));
+    assert!(is_cloexec(&slave));
+    assert!(is_cloexec(&Slave::new(&path).unwrap()));
+    assert!(is_cloexec(&Master::new("/dev/ptmx").unwrap()));
+}
+
+#[test]
+fn it_closes_explicitly() {
+    let (master, slave) = pty::open().unwrap();
+
+    assert!(slave.close().is_ok());
+    assert!(master.close().is_ok());
+}
@@ -0,0 +1,50 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::time::Duration;
+
+#[test]
+fn it_exit_notifier() {
+    let child = pty::Command::new("sh").args(["-c", "sleep 0.1; exit 3"]).spawn().unwrap();
+    let notifier = ExitNotifier::new(child.process()).unwrap();
+
+    assert_eq!(notifier.pid(), child.pid());
+    assert!(notifier.try_wait().unwrap().is_none());
+
+    let status = loop {
+        let mut fds = [PollFd::new(&notifier, Interest::Readable)];
+
+        assert_eq!(pty::poll::poll(&mut fds, Some(Duration::from_secs(5))).unwrap(), 1);
+        if let Some(status) = notifier.try_wait().unwrap() {
+            break status;
+        }
+    };
+    assert_eq!(status.code(), Some(3));
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+    assert!(child.kill(Signal::Kill).is_ok());
+}
+
+#[test]
+fn it_exit_notifier_many() {
+    let children: Vec<_> = (0..20)
+        .map(|_| pty::Command::new("sh").args(["-c", "exit 4"]).spawn().unwrap())
+        .collect();
+    let notifiers: Vec<ExitNotifier> = children.iter()
+        .map(|child| ExitNotifier::new(child.process()).unwrap())
+        .collect();
+
+    for notifier in &notifiers {
+        let status = loop {
+            if let Some(status) = notifier.try_wait().unwrap() {
+                break status;
+            }
+
+            let mut fds = [PollFd::new(notifier, Interest::Readable)];
+
+            assert_eq!(pty::poll::poll(&mut fds, Some(Duration::from_secs(5))).unwrap(), 1);
+        };
+        assert_eq!(status.code(), Some(4));
+    }
+}
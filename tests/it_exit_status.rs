@@ -0,0 +1,64 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::process;
+use std::time::Duration;
+
+#[test]
+fn it_exit_status() {
+    let fork = Fork::from_ptmx().unwrap();
+
+    if fork.is_parent().is_ok() {
+        let status = fork.wait().unwrap();
+
+        assert!(!status.success());
+        assert_eq!(status.code(), Some(3));
+        assert_eq!(status.signal(), None);
+        assert_eq!(process::ExitStatus::from(status).code(), Some(3));
+        assert_eq!(fork.wait().unwrap(), status);
+        assert_eq!(fork.try_wait().unwrap(), Some(status));
+        assert!(fork.kill(Signal::Kill).is_ok());
+    } else {
+        unsafe {
+            libc::_exit(3);
+        }
+    }
+}
+
+#[test]
+fn it_exit_status_of_signal() {
+    let child = pty::Command::new("sh").args(["-c", "kill -KILL $$"]).spawn().unwrap();
+    let status = child.wait().unwrap();
+
+    assert_eq!(status.code(), None);
+    assert_eq!(status.signal(), Some(libc::SIGKILL));
+    assert!(!status.core_dumped());
+}
+
+#[test]
+fn it_try_wait_and_wait_timeout() {
+    let child = pty::Command::new("sleep").arg("0.3").spawn().unwrap();
+
+    assert_eq!(child.try_wait().unwrap(), None);
+    assert_eq!(child.wait_timeout(Duration::from_millis(10)).unwrap(), None);
+
+    let status = child.wait_timeout(Duration::from_secs(10)).unwrap();
+
+    assert!(status.unwrap().success());
+}
+
+#[test]
+fn it_keeps_the_exit_status() {
+    let child = pty::Command::new("sh").args(["-c", "exit 5"]).spawn().unwrap();
+    let status = child.wait().unwrap();
+
+    assert_eq!(child.wait().unwrap(), status);
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+    assert_eq!(child.wait_timeout(Duration::from_millis(10)).unwrap(), Some(status));
+    assert_eq!(child.shutdown(ShutdownPolicy::default()).unwrap(), status);
+    assert!(child.kill(Signal::Kill).is_ok());
+    assert!(child.kill_group(Signal::Kill).is_ok());
+    assert!(child.kill_foreground(Signal::Kill).is_ok());
+}
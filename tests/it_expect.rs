@@ -0,0 +1,123 @@
+extern crate pty;
+
+use self::pty::prelude::*;
+
+use std::time::Duration;
+
+fn spawn(script: &str) -> (pty::command::Child, Expect) {
+    let child = pty::Command::new("sh")
+        .args(["-c", script])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let expect = Expect::new(child.master().try_clone().unwrap());
+
+    (child, expect)
+}
+
+#[test]
+fn it_expect_exact() {
+    let (child, mut expect) = spawn("echo ready; read line; echo got $line");
+    let ready = expect.expect("ready").unwrap();
+
+    assert_eq!(ready.as_bytes(), b"ready");
+    assert!(ready.before().is_empty());
+
+    expect.send_line("hi").unwrap();
+    assert_eq!(expect.expect("hi").unwrap().before(), b"\r\ngot ");
+
+    let end = expect.expect(Pattern::Eof).unwrap();
+
+    assert_eq!(end.before(), b"\r\n");
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_expect_any() {
+    let (child, mut expect) = spawn("echo second");
+    let found = expect.expect_any(&["first".into(), "second".into(), Pattern::Eof]).unwrap();
+
+    assert_eq!(found.index(), 1);
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_expect_timeout() {
+    let (child, mut expect) = spawn("echo partial; sleep 5");
+
+    expect.timeout(Some(Duration::from_millis(300)));
+
+    let err = expect.expect("never").unwrap_err();
+
+    match err {
+        ExpectError::Timeout { ref transcript } => assert_eq!(transcript, b"partial\r\n"),
+        ref err => panic!("unexpected error: {}", err),
+    }
+    assert!(err.to_string().contains("partial"));
+    assert_eq!(expect.expect(Pattern::Timeout).unwrap().before(), b"partial\r\n");
+    child.kill(Signal::Kill).unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn it_expect_eof() {
+    let (child, mut expect) = spawn("echo bye");
+    let err = expect.expect("never").unwrap_err();
+
+    assert_eq!(err.transcript(), Some(&b"bye\r\n"[..]));
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_expect_send_control() {
+    let (child, mut expect) = spawn("trap 'echo interrupted; exit 0' INT; echo ready; while :; do sleep 1; done");
+
+    expect.expect("ready").unwrap();
+    expect.send_control('c').unwrap();
+    expect.expect("interrupted").unwrap();
+    assert!(expect.send_control('1').is_err());
+    child.wait().unwrap();
+}
+
+#[test]
+fn it_expect_rolling_buffer() {
+    let (child, mut expect) = spawn("echo 0123456789");
+
+    expect.buffer_size(4);
+
+    let err = expect.expect("never").unwrap_err();
+
+    assert_eq!(err.transcript(), Some(&b"89\r\n"[..]));
+    assert!(child.wait().unwrap().success());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn it_expect_regex() {
+    extern crate regex;
+
+    let (child, mut expect) = spawn("echo version 1.23 ok");
+    let found = expect.expect(regex::bytes::Regex::new(r"version (\d+)\.(\d+)").unwrap()).unwrap();
+
+    assert_eq!(found.as_bytes(), b"version 1.23");
+    assert_eq!(found.get(1), Some(&b"1"[..]));
+    assert_eq!(found.get(2), Some(&b"23"[..]));
+    assert_eq!(found.get(3), None);
+    assert_eq!(expect.expect("ok").unwrap().before(), b" ");
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_expect_send_nonblocking() {
+    let (child, mut expect) = spawn("cat > /dev/null; echo done");
+    let line = [b'a'; 1023];
+
+    expect.get_ref().set_nonblocking(true).unwrap();
+    for _ in 0..256 {
+        expect.send(&line[..]).unwrap();
+        expect.send("\n").unwrap();
+    }
+    expect.send_control('d').unwrap();
+    expect.expect("done").unwrap();
+    assert!(child.wait().unwrap().success());
+}
@@ -12,10 +12,10 @@ use std::string::String;
 fn it_fork_with_new_pty() {
     let fork = Fork::from_ptmx().unwrap();
 
-    if let Some(mut master) = fork.is_parent().ok() {
+    if let Ok(mut master) = fork.is_parent() {
         let mut string = String::new();
 
-        master.read_to_string(&mut string).unwrap_or_else(|e| panic!(e));
+        master.read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
 
         let output = Command::new("tty")
             .stdin(Stdio::inherit())
@@ -27,7 +27,7 @@ fn it_fork_with_new_pty() {
         let parent_tty = output_str.trim();
         let child_tty = string.trim();
 
-        assert!(child_tty != "");
+        assert!(!child_tty.is_empty());
         assert!(child_tty != parent_tty);
 
         // only compare if parent is tty
@@ -0,0 +1,60 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd};
+use std::process::Command;
+
+fn is_open(fd: libc::c_int) -> bool {
+    unsafe { libc::fcntl(fd, libc::F_GETFD) != -1 }
+}
+
+#[test]
+fn it_master_try_clone() {
+    let (master, slave) = pty::open().unwrap();
+    let mut clone = master.try_clone().unwrap();
+    let fd = master.as_raw_fd();
+
+    assert_ne!(clone.as_raw_fd(), fd);
+    assert!(unsafe { libc::fcntl(clone.as_raw_fd(), libc::F_GETFD) } & libc::FD_CLOEXEC != 0);
+
+    drop(master);
+
+    let mut slave_writer = unsafe { std::fs::File::from_raw_fd(libc::dup(slave.as_raw_fd())) };
+    let mut buf = [0; 64];
+
+    slave_writer.write_all(b"hi\n").unwrap();
+    let len = clone.read(&mut buf).unwrap();
+    assert_eq!(String::from_utf8_lossy(&buf[..len]).trim(), "hi");
+}
+
+#[test]
+fn it_master_raw_fd_round_trip() {
+    let (master, _slave) = pty::open().unwrap();
+    let fd = master.into_raw_fd();
+
+    assert!(is_open(fd));
+
+    let master = unsafe { Master::from_raw_fd(fd) };
+
+    assert_eq!(master.as_raw_fd(), fd);
+}
+
+#[test]
+fn it_fork_lends_the_master() {
+    let fork = Fork::from_ptmx().unwrap();
+
+    if let Ok(mut master) = fork.is_parent() {
+        let fd = master.as_raw_fd();
+        let mut output = String::new();
+
+        assert_eq!(fork.is_parent().unwrap().as_raw_fd(), fd);
+        master.read_to_string(&mut output).unwrap();
+        assert_eq!(output.trim(), "hi");
+        fork.wait().unwrap();
+    } else {
+        Command::new("echo").arg("hi").status().expect("could not execute echo");
+    }
+}
@@ -0,0 +1,58 @@
+extern crate pty;
+extern crate libc;
+
+use std::io::prelude::*;
+use std::io::ErrorKind;
+use std::{mem, ptr, thread};
+use std::time::Duration;
+
+extern "C" fn ignore(_: libc::c_int) {}
+
+#[test]
+fn it_read_eof_on_hangup() {
+    let child = pty::Command::new("echo").arg("done").spawn().unwrap();
+    let mut master = child.master();
+    let mut string = String::new();
+
+    master.read_to_string(&mut string).unwrap();
+
+    assert_eq!(string.trim(), "done");
+    assert_eq!(master.read(&mut [0; 8]).unwrap(), 0);
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_read_would_block() {
+    let child = pty::Command::new("sleep").arg("1").spawn().unwrap();
+    let mut master = child.master();
+
+    master.set_nonblocking(true).unwrap();
+
+    assert_eq!(master.read(&mut [0; 8]).unwrap_err().kind(), ErrorKind::WouldBlock);
+}
+
+#[test]
+fn it_read_retries_interrupt() {
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        let handler: extern "C" fn(libc::c_int) = ignore;
+
+        // Without `SA_RESTART`, the blocking read fails with `EINTR`.
+        action.sa_sigaction = handler as libc::sighandler_t;
+        assert_eq!(libc::sigaction(libc::SIGUSR2, &action, ptr::null_mut()), 0);
+    }
+
+    let child = pty::Command::new("sh").args(["-c", "sleep 0.5; echo done"]).spawn().unwrap();
+    let mut master = child.master();
+    let reader = unsafe { libc::pthread_self() };
+    let interrupter = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        unsafe { libc::pthread_kill(reader, libc::SIGUSR2) }
+    });
+    let mut buf = [0; 64];
+    let len = master.read(&mut buf).unwrap();
+
+    assert_eq!(interrupter.join().unwrap(), 0);
+    assert_eq!(String::from_utf8_lossy(&buf[..len]).trim(), "done");
+    assert!(child.wait().unwrap().success());
+}
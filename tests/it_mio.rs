@@ -0,0 +1,65 @@
+#![cfg(feature = "mio")]
+
+extern crate pty;
+extern crate libc;
+extern crate mio;
+
+use self::pty::prelude::*;
+
+use self::mio::{Events, Interest, Poll, Token};
+use std::io::prelude::*;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+const MASTER: Token = Token(0);
+const EXIT: Token = Token(1);
+
+#[test]
+fn it_mio() {
+    let mut poll = Poll::new().unwrap();
+    let mut events = Events::with_capacity(8);
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line; exit 5"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let mut notifier = ExitNotifier::new(child.process()).unwrap();
+    let mut master = child.master().try_clone().unwrap();
+
+    poll.registry().register(&mut master, MASTER, Interest::READABLE).unwrap();
+    poll.registry().register(&mut notifier, EXIT, Interest::READABLE).unwrap();
+
+    let _ = master.write("hi\n".to_string().as_bytes());
+
+    let mut output = String::new();
+    let mut status = None;
+
+    while status.is_none() {
+        match poll.poll(&mut events, Some(Duration::from_secs(5))) {
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            result => result.unwrap(),
+        }
+        assert!(!events.is_empty());
+
+        for event in events.iter() {
+            match event.token() {
+                MASTER => {
+                    let mut buf = [0; 64];
+
+                    loop {
+                        match master.read(&mut buf) {
+                            Ok(0) => break,
+                            Ok(len) => output.push_str(&String::from_utf8_lossy(&buf[..len])),
+                            Err(ref e) if e.kind() == ErrorKind::WouldBlock => break,
+                            Err(e) => panic!("{}", e),
+                        }
+                    }
+                }
+                EXIT => status = notifier.try_wait().unwrap(),
+                _ => unreachable!(),
+            }
+        }
+    }
+    assert_eq!(output.trim(), "got hi");
+    assert_eq!(status.unwrap().code(), Some(5));
+}
@@ -0,0 +1,56 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+#[test]
+fn it_nonblocking() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let mut master = child.master();
+    let mut buf = [0; 64];
+
+    master.set_nonblocking(true).unwrap();
+
+    assert_eq!(master.read(&mut buf).unwrap_err().kind(), ErrorKind::WouldBlock);
+    assert!(!master.wait_readable(Some(Duration::from_millis(50))).unwrap());
+    assert!(master.wait_writable(Some(Duration::from_secs(5))).unwrap());
+
+    let _ = master.write("hi\n".to_string().as_bytes());
+    let mut string = String::new();
+
+    while !string.contains('\n') {
+        assert!(master.wait_readable(Some(Duration::from_secs(5))).unwrap());
+        match master.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => string.push_str(&String::from_utf8_lossy(&buf[..len])),
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    assert_eq!(string.trim(), "got hi");
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_poll_several_masters() {
+    let quiet = pty::Command::new("sleep").arg("10").spawn().unwrap();
+    let talkative = pty::Command::new("echo").arg("hello").spawn().unwrap();
+    let mut fds = [PollFd::new(quiet.master(), Interest::Readable),
+                   PollFd::new(talkative.master(), Interest::Readable)];
+
+    assert_eq!(pty::poll::poll(&mut fds, Some(Duration::from_secs(5))).unwrap(), 1);
+    assert!(fds[0].readiness().is_empty());
+    assert!(fds[1].readiness().is_readable());
+
+    quiet.kill(Signal::Kill).unwrap();
+    let _ = quiet.wait();
+    let _ = talkative.wait();
+}
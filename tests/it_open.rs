@@ -0,0 +1,57 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::process::{Command, Stdio};
+
+#[test]
+fn it_open() {
+    let (mut master, slave) = pty::open().unwrap();
+    let stdout = unsafe { File::from_raw_fd(libc::dup(slave.as_raw_fd())) };
+    let status = Command::new("echo").arg("hi").stdout(Stdio::from(stdout)).status().unwrap();
+    let mut buf = [0; 64];
+    let len = master.read(&mut buf).unwrap();
+
+    assert!(status.success());
+    assert_eq!(String::from_utf8_lossy(&buf[..len]).trim(), "hi");
+    assert_eq!(unsafe { libc::tcgetsid(slave.as_raw_fd()) }, -1);
+}
+
+#[test]
+fn it_open_with() {
+    let size = WindowSize::new(24, 80);
+    let (_master, slave) = pty::open_with(Some(size), Some(|termios| termios.echo(false))).unwrap();
+
+    assert_eq!(slave.window_size().unwrap(), size);
+    assert!(!slave.termios().unwrap().is_echo());
+}
+
+#[test]
+fn it_slave_path() {
+    let (master, slave) = pty::open().unwrap();
+    let path = master.slave_path().unwrap();
+    let other = Slave::new(&path).unwrap();
+
+    assert!(path.starts_with("/dev/"));
+    assert_eq!(other.window_size().unwrap(), slave.window_size().unwrap());
+    assert!(Master::new("/nonexistent/ptmx").is_err());
+}
+
+#[test]
+fn it_slave_from_master() {
+    let (master, _slave) = pty::open().unwrap();
+    let peer = Slave::from_master(&master).unwrap();
+    let by_path = Slave::new(master.slave_path().unwrap()).unwrap();
+    let rdev = |slave: &Slave| unsafe {
+        let mut stat: libc::stat = std::mem::zeroed();
+
+        assert_eq!(libc::fstat(slave.as_raw_fd(), &mut stat), 0);
+        stat.st_rdev
+    };
+
+    assert_eq!(rdev(&peer), rdev(&by_path));
+}
@@ -0,0 +1,62 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::error::Error;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::panic;
+
+#[test]
+fn it_raw_mode_guard() {
+    let child = pty::Command::new("cat").spawn().unwrap();
+    let fd = child.master().as_raw_fd();
+
+    assert!(child.master().termios().unwrap().is_echo());
+
+    {
+        let guard = RawModeGuard::new(fd).unwrap();
+
+        assert!(guard.original().is_echo());
+        assert!(!child.master().termios().unwrap().is_echo());
+        assert!(!child.master().termios().unwrap().is_canonical());
+    }
+    assert!(child.master().termios().unwrap().is_echo());
+    assert!(child.master().termios().unwrap().is_canonical());
+
+    let result = panic::catch_unwind(|| {
+        let _guard = RawModeGuard::new(fd).unwrap();
+
+        panic!("restore on unwind");
+    });
+
+    assert!(result.is_err());
+    assert!(child.master().termios().unwrap().is_echo());
+    assert!(child.master().termios().unwrap().is_canonical());
+}
+
+#[test]
+fn it_raw_mode_guard_fails_without_terminal() {
+    match RawModeGuard::new(-1) {
+        Err(RawModeError::TcgetattrFail(err)) => assert_eq!(err.raw_os_error(), Some(libc::EBADF)),
+        other => panic!("unexpected: {:?}", other),
+    }
+}
+
+#[test]
+fn it_raw_mode_error_keeps_errno() {
+    let err = RawModeGuard::new(-1).unwrap_err();
+    let ebadf = io::Error::from_raw_os_error(libc::EBADF);
+
+    assert_eq!(err.to_string(), format!("tcgetattr: {}", ebadf));
+    assert_eq!(err.source()
+                   .and_then(|cause| cause.downcast_ref::<io::Error>())
+                   .and_then(|cause| cause.raw_os_error()),
+               Some(libc::EBADF));
+
+    let err = io::Error::from(err);
+
+    assert_eq!(err.kind(), ebadf.kind());
+    assert_eq!(err.to_string(), format!("tcgetattr: {}", ebadf));
+}
@@ -0,0 +1,44 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::os::unix::io::AsRawFd;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn it_resize_forwarder() {
+    let terminal = pty::Command::new("cat").spawn().unwrap();
+    let child = pty::Command::new("cat").spawn().unwrap();
+    let size = WindowSize::new(33, 99);
+
+    terminal.master().set_window_size(size).unwrap();
+
+    let forwarder = ResizeForwarder::from_fd(terminal.master().as_raw_fd(), child.master().try_clone().unwrap())
+        .unwrap();
+
+    for _ in 0..100 {
+        if child.master().window_size().unwrap() == size {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(child.master().window_size().unwrap(), size);
+
+    let size = WindowSize::new(12, 34);
+
+    terminal.master().set_window_size(size).unwrap();
+    unsafe {
+        libc::kill(libc::getpid(), libc::SIGWINCH);
+    }
+    for _ in 0..100 {
+        if child.master().window_size().unwrap() == size {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(child.master().window_size().unwrap(), size);
+
+    drop(forwarder);
+}
@@ -0,0 +1,101 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+
+fn pipe() -> (File, File) {
+    let mut fds = [0; 2];
+
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) }
+}
+
+#[test]
+fn it_session_runs_until_exit() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line; exit 3"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let (input, mut keystrokes) = pipe();
+    let (mut output, screen) = pipe();
+
+    keystrokes.write_all(b"hi\n").unwrap();
+
+    let outcome = Session::new(&child)
+        .input(input.as_raw_fd())
+        .output(screen.as_raw_fd())
+        .run()
+        .unwrap();
+    let mut printed = String::new();
+
+    drop(screen);
+    output.read_to_string(&mut printed).unwrap();
+
+    match outcome {
+        Outcome::Exited(status) => {
+            assert_eq!(status.code(), Some(3));
+            assert_eq!(child.wait().unwrap(), status);
+            assert_eq!(child.try_wait().unwrap(), Some(status));
+            child.kill(Signal::Kill).unwrap();
+        }
+        Outcome::Detached => panic!("detached without escape key"),
+    }
+    assert_eq!(printed.trim(), "got hi");
+}
+
+#[test]
+fn it_session_forwards_end_of_input() {
+    let child = pty::Command::new("cat").spawn().unwrap();
+    let (input, mut keystrokes) = pipe();
+    let (mut output, screen) = pipe();
+
+    keystrokes.write_all(b"hi\n").unwrap();
+    drop(keystrokes);
+
+    let outcome = Session::new(&child)
+        .input(input.as_raw_fd())
+        .output(screen.as_raw_fd())
+        .run()
+        .unwrap();
+    let mut printed = String::new();
+
+    drop(screen);
+    output.read_to_string(&mut printed).unwrap();
+
+    assert!(match outcome {
+        Outcome::Exited(status) => status.success(),
+        Outcome::Detached => false,
+    });
+    assert!(printed.contains("hi"));
+}
+
+#[test]
+fn it_session_detaches_on_escape() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let (input, mut keystrokes) = pipe();
+    let (_output, screen) = pipe();
+
+    keystrokes.write_all(b"hi\n\x1dignored\n").unwrap();
+
+    let outcome = Session::new(&child)
+        .input(input.as_raw_fd())
+        .output(screen.as_raw_fd())
+        .escape(Some(pty::session::DEFAULT_ESCAPE))
+        .run()
+        .unwrap();
+    let mut printed = String::new();
+
+    assert_eq!(outcome, Outcome::Detached);
+    { child.master() }.read_to_string(&mut printed).unwrap();
+    assert_eq!(printed.trim(), "got hi");
+    assert!(child.wait().unwrap().success());
+}
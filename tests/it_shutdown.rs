@@ -0,0 +1,37 @@
+extern crate pty;
+extern crate libc;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use self::common::read_line;
+use self::pty::prelude::*;
+
+use std::time::{Duration, Instant};
+
+#[test]
+fn it_shutdown_with_hangup() {
+    let child = pty::Command::new("sleep").arg("10").spawn().unwrap();
+    let status = child.shutdown(ShutdownPolicy::default()).unwrap();
+
+    assert_eq!(status.signal(), Some(libc::SIGHUP));
+}
+
+#[test]
+fn it_shutdown_escalates_to_kill() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "trap '' HUP TERM; echo ready; while :; do sleep 1; done"])
+        .spawn()
+        .unwrap();
+    let master = child.master();
+
+    assert_eq!(read_line(master).trim(), "ready");
+
+    let policy = ShutdownPolicy::new(Duration::from_millis(100), Duration::from_millis(100));
+    let started = Instant::now();
+    let status = child.shutdown(policy).unwrap();
+
+    assert_eq!(status.signal(), Some(libc::SIGKILL));
+    assert!(started.elapsed() >= Duration::from_millis(200));
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+}
@@ -0,0 +1,45 @@
+extern crate pty;
+extern crate libc;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use self::common::read_line;
+use self::pty::prelude::*;
+
+#[test]
+fn it_kill() {
+    let child = pty::Command::new("sleep").arg("10").spawn().unwrap();
+
+    child.kill(Signal::Terminate).unwrap();
+
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGTERM));
+}
+
+#[test]
+fn it_kill_foreground() {
+    let child = pty::Command::new("sh").args(["-c", "echo ready; sleep 10; echo done"]).spawn().unwrap();
+    let master = child.master();
+
+    assert_eq!(read_line(master).trim(), "ready");
+    assert_eq!(master.foreground_process_group().unwrap(), child.pid());
+
+    child.kill_foreground(Signal::Kill).unwrap();
+
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+}
+
+#[test]
+fn it_kill_group() {
+    let fork = Fork::from_ptmx().unwrap();
+
+    if let Ok(master) = fork.is_parent() {
+        assert_eq!(read_line(master).trim(), "ready");
+
+        fork.kill_group(Signal::Hangup).unwrap();
+
+        assert_eq!(fork.wait().unwrap().signal(), Some(libc::SIGHUP));
+    } else {
+        let _ = std::process::Command::new("sh").args(["-c", "echo ready; sleep 10"]).status();
+    }
+}
@@ -0,0 +1,51 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::string::String;
+
+#[test]
+fn it_spawn_command() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "tty; pwd; echo $PTY_TEST"])
+        .env("PTY_TEST", "spawned!")
+        .current_dir("/")
+        .spawn()
+        .unwrap();
+    let mut string = String::new();
+
+    child.master().read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+    let lines: Vec<&str> = string.lines().map(|line| line.trim()).collect();
+
+    assert!(lines[0].starts_with("/dev/"));
+    assert_eq!(lines[1], "/");
+    assert_eq!(lines[2], "spawned!");
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn it_spawn_command_fails_on_nul_byte() {
+    match pty::Command::new("sh").arg("a\0b").spawn() {
+        Err(CommandError::NulByte) => {}
+        other => panic!("unexpected: {:?}", other),
+    }
+}
+
+#[test]
+fn it_spawn_command_reports_exec_failure() {
+    match pty::Command::new("/nonexistent/program").spawn() {
+        Err(CommandError::Spawn(SpawnError::Exec { errno })) => assert_eq!(errno, libc::ENOENT),
+        other => panic!("unexpected: {:?}", other),
+    }
+}
+
+#[test]
+fn it_spawn_command_reports_chdir_failure() {
+    match pty::Command::new("true").current_dir("/nonexistent").spawn() {
+        Err(CommandError::Spawn(SpawnError::Chdir { errno })) => assert_eq!(errno, libc::ENOENT),
+        other => panic!("unexpected: {:?}", other),
+    }
+}
@@ -0,0 +1,26 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::process::Command;
+use std::string::String;
+
+#[test]
+fn it_spawn_pty() {
+    let mut command = Command::new("sh");
+
+    command.args(["-c", "tty; echo controlling > /dev/tty"]);
+
+    let mut child = command.spawn_pty(None, None).unwrap();
+    let mut string = String::new();
+
+    child.master().read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+    let lines: Vec<&str> = string.lines().map(|line| line.trim()).collect();
+
+    assert!(lines[0].starts_with("/dev/"));
+    assert_eq!(lines[1], "controlling");
+    assert!(child.child().wait().unwrap().success());
+}
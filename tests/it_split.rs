@@ -0,0 +1,28 @@
+extern crate pty;
+
+use std::io::prelude::*;
+use std::os::unix::io::AsRawFd;
+use std::thread;
+
+#[test]
+fn it_split() {
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let (mut reader, mut writer) = child.master().try_clone().unwrap().split();
+
+    assert_eq!(reader.as_raw_fd(), writer.as_raw_fd());
+
+    let output = thread::spawn(move || {
+        let mut output = String::new();
+
+        reader.read_to_string(&mut output).map(|_| output)
+    });
+
+    writer.write_all(b"hi\n").unwrap();
+
+    assert_eq!(output.join().unwrap().unwrap().trim(), "got hi");
+    assert!(child.wait().unwrap().success());
+}
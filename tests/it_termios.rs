@@ -0,0 +1,48 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::process::Command;
+use std::string::String;
+
+#[test]
+fn it_termios() {
+    let fork = Fork::from_ptmx().unwrap();
+
+    if let Ok(mut master) = fork.is_parent() {
+        let mut string = String::new();
+
+        master.read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+        assert_eq!(string.trim(), "-icanon -echo");
+    } else {
+        let slave = fork.is_child().unwrap();
+        let termios = slave.termios().unwrap().echo(false).canonical(false);
+
+        slave.set_termios(&termios).unwrap();
+        assert!(!slave.termios().unwrap().is_echo());
+        assert!(!slave.termios().unwrap().is_canonical());
+
+        Command::new("sh")
+            .args(["-c", "stty -a | grep -o -- '-echo \\|-icanon' | tr -d ' ' | xargs echo"])
+            .status()
+            .expect("could not execute stty");
+    }
+}
+
+#[test]
+fn it_termios_at_spawn() {
+    let child = pty::Command::new("cat").termios(|termios| termios.echo(false)).spawn().unwrap();
+    let mut master = child.master();
+
+    let _ = master.write("no echo\n".to_string().as_bytes());
+    let _ = master.write(&[4]);
+
+    let mut string = String::new();
+
+    master.read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+    assert_eq!(string, "no echo\r\n");
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "tokio")]
+
+extern crate pty;
+extern crate libc;
+extern crate tokio;
+
+use self::pty::aio::tokio::{AsyncMaster, Exit};
+
+use self::tokio::io::{AsyncReadExt, AsyncWriteExt};
+use self::tokio::runtime::Builder;
+
+#[test]
+fn it_tokio() {
+    let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+    let _context = runtime.enter();
+
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line; exit 7"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let exit = Exit::new(child.process()).unwrap();
+    let mut master = AsyncMaster::new(child.master().try_clone().unwrap()).unwrap();
+    let mut output = Vec::new();
+
+    runtime.block_on(master.write_all(b"hi\n")).unwrap();
+    runtime.block_on(master.read_to_end(&mut output)).unwrap();
+
+    assert_eq!(String::from_utf8_lossy(&output).trim(), "got hi");
+    let status = runtime.block_on(exit).unwrap();
+
+    assert_eq!(status.code(), Some(7));
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+    assert_eq!(child.wait().unwrap(), status);
+}
+
+#[test]
+fn it_tokio_split() {
+    let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+    let _context = runtime.enter();
+
+    let child = pty::Command::new("sh")
+        .args(["-c", "read line; echo got $line"])
+        .termios(|termios| termios.echo(false))
+        .spawn()
+        .unwrap();
+    let master = AsyncMaster::new(child.master().try_clone().unwrap()).unwrap();
+    let (mut reader, mut writer) = master.split();
+    let mut output = Vec::new();
+
+    runtime.block_on(writer.write_all(b"hi\n")).unwrap();
+    runtime.block_on(reader.read_to_end(&mut output)).unwrap();
+
+    assert_eq!(String::from_utf8_lossy(&output).trim(), "got hi");
+    assert!(child.wait().unwrap().success());
+}
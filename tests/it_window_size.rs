@@ -0,0 +1,47 @@
+extern crate pty;
+extern crate libc;
+
+use self::pty::prelude::*;
+
+use std::io::prelude::*;
+use std::process::Command;
+use std::string::String;
+
+#[test]
+fn it_window_size() {
+    let size = WindowSize::new(24, 80);
+    let child = pty::Command::new("stty").arg("size").window_size(size).spawn().unwrap();
+    let mut string = String::new();
+
+    child.master().read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+    assert_eq!(string.trim(), "24 80");
+    assert_eq!(child.master().window_size().unwrap(), size);
+}
+
+#[test]
+fn it_set_window_size() {
+    let mut command = Command::new("sh");
+
+    command.args(["-c", "read line; stty size"]);
+
+    let mut child = command.spawn_pty(None, None).unwrap();
+    let mut master = child.master();
+    let size = WindowSize {
+        rows: 50,
+        cols: 132,
+        x_pixels: 1320,
+        y_pixels: 1000,
+    };
+
+    master.set_window_size(size).unwrap();
+    assert_eq!(master.window_size().unwrap(), size);
+
+    let _ = master.write("\n".to_string().as_bytes());
+    let mut string = String::new();
+
+    master.read_to_string(&mut string).unwrap_or_else(|e| panic!("{}", e));
+
+    assert_eq!(string.lines().last().unwrap().trim(), "50 132");
+    assert!(child.child().wait().unwrap().success());
+}
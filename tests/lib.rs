@@ -1,2 +1,29 @@
+// The helpers of tests/common are loaded by each test file, which
+// is also built alone.
+#![allow(clippy::duplicate_mod)]
+
 mod it_fork_with_new_pty;
 mod it_can_read_write;
+mod it_spawn_command;
+mod it_spawn_pty;
+mod it_window_size;
+mod it_resize_forwarder;
+mod it_termios;
+mod it_raw_mode_guard;
+mod it_exit_status;
+mod it_signal;
+mod it_shutdown;
+mod it_nonblocking;
+mod it_master_read;
+mod it_controlling_terminal;
+mod it_open;
+mod it_master_owned;
+mod it_descriptor;
+mod it_split;
+mod it_session;
+mod it_expect;
+mod it_tokio;
+mod it_exit_notifier;
+mod it_mio;
+mod it_async_io;
+mod it_closed_stdio;